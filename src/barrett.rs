@@ -0,0 +1,76 @@
+use crate::u256::Uint256;
+use crate::u512::Uint512;
+
+/// Barrett reduction modulo a fixed 256-bit modulus.
+///
+/// Precomputes `mu = floor((2^512 - 1) / m)` once, then reduces a 512-bit
+/// value with two multiplications and a few subtractions instead of a
+/// 512-by-256 division. Unlike [`Montgomery256`](crate::Montgomery256) the
+/// modulus may be even, and values stay in ordinary form.
+///
+/// Using `2^512 - 1` rather than `2^512` keeps `mu` in 512 bits even for
+/// `m = 1`; the estimate it gives is at most one lower, which the final
+/// correction absorbs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Barrett256 {
+    modulus: Uint256,
+    mu: Uint512,
+}
+
+impl Barrett256 {
+    /// Context for `modulus`, or `None` if it is zero.
+    pub fn new(modulus: Uint256) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let mu = Uint512::MAX / Uint512::from(modulus);
+        Some(Self { modulus, mu })
+    }
+
+    pub fn modulus(&self) -> Uint256 {
+        self.modulus
+    }
+
+    /// `x mod m` for a 512-bit `x` given as `(high, low)` halves, the shape
+    /// `Uint256::widening_mul` returns.
+    ///
+    /// The quotient estimate `floor(x * mu / 2^512)` is never above the true
+    /// quotient and at most two below it, so the remainder is brought under
+    /// `m` by at most two subtractions.
+    pub fn reduce(&self, x: (Uint256, Uint256)) -> Uint256 {
+        let x = Uint512::from_halves(x.0, x.1);
+        let m = Uint512::from(self.modulus);
+
+        let q = mul_high(x, self.mu);
+        // q * m <= x, so neither the product nor the difference wraps.
+        let mut r = x - q * m;
+        while r >= m {
+            r -= m;
+        }
+        r.split().1
+    }
+
+    /// `(a * b) mod m`.
+    pub fn mulmod(&self, a: Uint256, b: Uint256) -> Uint256 {
+        self.reduce(a.widening_mul(b))
+    }
+}
+
+/// High 512 bits of the 1024-bit product `a * b`.
+fn mul_high(a: Uint512, b: Uint512) -> Uint512 {
+    let a = a.to_array();
+    let b = b.to_array();
+    let mut out = [0u64; 16];
+    for i in 0..8 {
+        let mut carry = 0u64;
+        for j in 0..8 {
+            let t = a[i] as u128 * b[j] as u128 + out[i + j] as u128 + carry as u128;
+            out[i + j] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        out[i + 8] = carry;
+    }
+    let mut hi = [0u64; 8];
+    hi.copy_from_slice(&out[8..]);
+    Uint512::from_array(hi)
+}
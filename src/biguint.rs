@@ -0,0 +1,164 @@
+use crate::u256::Uint256;
+use crate::u512::Uint512;
+use std::cmp::Ordering;
+
+/// Fixed-width unsigned integer of `LIMBS` 64-bit limbs.
+///
+/// Limbs are stored least significant first: `self.0[0]` holds bits 0-63.
+/// All arithmetic is written as loops over the limb count, so any width is
+/// available without another hand-written type. The concrete types such as
+/// [`Uint256`] keep their unrolled operators for codegen; use this type when
+/// a width has no dedicated implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigUint<const LIMBS: usize>(pub [u64; LIMBS]);
+
+impl<const LIMBS: usize> BigUint<LIMBS> {
+    pub const ZERO: Self = Self([0; LIMBS]);
+    pub const ONE: Self = {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = 1;
+        Self(limbs)
+    };
+    pub const MIN: Self = Self::ZERO;
+    pub const MAX: Self = Self([u64::MAX; LIMBS]);
+    pub const BITS: u32 = 64 * LIMBS as u32;
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&l| l == 0)
+    }
+
+    /// Addition returning the wrapped result and the carry out of the top limb.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let mut out = [0; LIMBS];
+        let mut carry = false;
+        for (i, limb) in out.iter_mut().enumerate() {
+            (*limb, carry) = self.0[i].carrying_add(rhs.0[i], carry);
+        }
+        (Self(out), carry)
+    }
+
+    /// Subtraction returning the wrapped result and the borrow out of the
+    /// top limb.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let mut out = [0; LIMBS];
+        let mut borrow = false;
+        for (i, limb) in out.iter_mut().enumerate() {
+            (*limb, borrow) = self.0[i].borrowing_sub(rhs.0[i], borrow);
+        }
+        (Self(out), borrow)
+    }
+
+    /// Multiplication returning the low `BITS` bits and whether any product
+    /// bit landed above them.
+    ///
+    /// Schoolbook over the lower triangle of limb pairs; pairs whose weight
+    /// is at or above `LIMBS` only contribute to the overflow flag.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let mut out = [0u64; LIMBS];
+        let mut overflow = false;
+        for i in 0..LIMBS {
+            let mut carry = 0u64;
+            for j in 0..LIMBS {
+                if i + j >= LIMBS {
+                    overflow |= self.0[i] != 0 && rhs.0[j] != 0;
+                    continue;
+                }
+                let t = self.0[i] as u128 * rhs.0[j] as u128 + out[i + j] as u128 + carry as u128;
+                out[i + j] = t as u64;
+                carry = (t >> 64) as u64;
+            }
+            overflow |= carry != 0;
+        }
+        (Self(out), overflow)
+    }
+
+    /// Checked addition. Returns `None` on carry out of the top limb.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on borrow out of the top limb.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` if the product needs more than
+    /// `BITS` bits.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+}
+
+impl<const LIMBS: usize> Default for BigUint<LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Add for BigUint<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.overflowing_add(rhs).0
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Sub for BigUint<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Mul for BigUint<LIMBS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.overflowing_mul(rhs).0
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for BigUint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for BigUint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<Uint256> for BigUint<4> {
+    fn from(v: Uint256) -> Self {
+        Self([v.l0, v.l1, v.l2, v.l3])
+    }
+}
+
+impl From<BigUint<4>> for Uint256 {
+    fn from(v: BigUint<4>) -> Self {
+        let [l0, l1, l2, l3] = v.0;
+        Self { l0, l1, l2, l3 }
+    }
+}
+
+impl From<Uint512> for BigUint<8> {
+    fn from(v: Uint512) -> Self {
+        Self(v.to_array())
+    }
+}
+
+impl From<BigUint<8>> for Uint512 {
+    fn from(v: BigUint<8>) -> Self {
+        Self::from_array(v.0)
+    }
+}
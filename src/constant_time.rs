@@ -0,0 +1,20 @@
+//! `subtle` integration (enabled by the `subtle` feature).
+//!
+//! Forwards to the inherent `Uint256::ct_eq` and `Uint256::ct_select`.
+
+use crate::Uint256;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+impl ConstantTimeEq for Uint256 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Choice::from(Uint256::ct_eq(self, other))
+    }
+}
+
+impl ConditionallySelectable for Uint256 {
+    /// `subtle` picks `b` when `choice` is set, the reverse of `ct_select`'s
+    /// argument order.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Uint256::ct_select(choice.unwrap_u8(), *b, *a)
+    }
+}
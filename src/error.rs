@@ -0,0 +1,76 @@
+//! Error types for parsing and conversions.
+//!
+//! `core::num::ParseIntError`, `core::num::TryFromIntError` and
+//! `core::array::TryFromSliceError` cannot be constructed outside the
+//! standard library, so the crate carries its own equivalents with the same
+//! messages.
+
+use std::fmt;
+
+/// Error returned when parsing an integer from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntError {
+    kind: IntErrorKind,
+}
+
+/// Reason a parse failed. Mirrors `core::num::IntErrorKind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntErrorKind {
+    /// The string was empty.
+    Empty,
+    /// The string contained a character that is not a digit in the radix.
+    InvalidDigit,
+    /// The value is too large for the target type.
+    PosOverflow,
+    /// The value is too small (too negative) for the target type.
+    NegOverflow,
+}
+
+impl ParseIntError {
+    pub(crate) const fn new(kind: IntErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// The reason the parse failed.
+    pub fn kind(&self) -> &IntErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            IntErrorKind::Empty => "cannot parse integer from empty string",
+            IntErrorKind::InvalidDigit => "invalid digit found in string",
+            IntErrorKind::PosOverflow => "number too large to fit in target type",
+            IntErrorKind::NegOverflow => "number too small to fit in target type",
+        })
+    }
+}
+
+impl std::error::Error for ParseIntError {}
+
+/// Error returned when a checked narrowing conversion fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromIntError(pub(crate) ());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+impl std::error::Error for TryFromIntError {}
+
+/// Error returned when a byte slice is too long for the target type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromSliceError(pub(crate) ());
+
+impl fmt::Display for TryFromSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("could not convert slice to array")
+    }
+}
+
+impl std::error::Error for TryFromSliceError {}
@@ -3,6 +3,9 @@
 //! Uses two's complement representation. Addition, subtraction, and wrapping
 //! multiplication are bitwise identical to unsigned operations.
 
+use crate::error::TryFromIntError;
+use crate::i64::Int64;
+use crate::u128::Uint128;
 use std::cmp::Ordering;
 
 /// 128-bit signed integer stored as two 64-bit limbs.
@@ -54,10 +57,53 @@ impl Int128 {
         (self.h as i128) << 64 | self.l as i128
     }
 
+    /// Little-endian byte representation, identical to `i128::to_le_bytes`.
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.to_i128().to_le_bytes()
+    }
+
+    /// Big-endian byte representation, identical to `i128::to_be_bytes`.
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        self.to_i128().to_be_bytes()
+    }
+
+    /// Create from little-endian bytes, identical to `i128::from_le_bytes`.
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self::from_i128(i128::from_le_bytes(bytes))
+    }
+
+    /// Create from big-endian bytes, identical to `i128::from_be_bytes`.
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        Self::from_i128(i128::from_be_bytes(bytes))
+    }
+
+    /// Native-endian byte representation, identical to `i128::to_ne_bytes`.
+    /// The field order follows `target_endian`, so this is also the
+    /// struct's own memory layout.
+    pub const fn to_ne_bytes(self) -> [u8; 16] {
+        if cfg!(target_endian = "big") { self.to_be_bytes() } else { self.to_le_bytes() }
+    }
+
+    /// Create from native-endian bytes, identical to `i128::from_ne_bytes`.
+    pub const fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        if cfg!(target_endian = "big") { Self::from_be_bytes(bytes) } else { Self::from_le_bytes(bytes) }
+    }
+
     pub fn is_zero(&self) -> bool {
         self.l == 0 && self.h == 0
     }
 
+    /// True if the lowest bit is clear. Zero is even.
+    pub const fn is_even(&self) -> bool {
+        self.l & 1 == 0
+    }
+
+    /// True if the lowest bit is set. Two's complement preserves parity,
+    /// so this is right for negative values as well.
+    pub const fn is_odd(&self) -> bool {
+        self.l & 1 == 1
+    }
+
     pub fn is_negative(&self) -> bool {
         (self.h as i64) < 0
     }
@@ -99,29 +145,53 @@ impl Int128 {
         }
     }
 
-    /// Helper for 64x64->128 multiplication (portable fallback).
-    #[cfg(not(target_arch = "x86_64"))]
-    fn mul_u64_full(a: u64, b: u64) -> (u64, u64) {
-        let a0 = a as u32 as u64;
-        let a1 = (a >> 32) as u32 as u64;
-        let b0 = b as u32 as u64;
-        let b1 = (b >> 32) as u32 as u64;
-
-        let p0 = a0 * b0;
-        let p1 = a0 * b1;
-        let p2 = a1 * b0;
-        let p3 = a1 * b1;
-
-        let (middle, carry_mid) = p1.overflowing_add(p2);
-        let (low, carry_low) = p0.overflowing_add(middle << 32);
-        let mut high = p3
-            .wrapping_add(middle >> 32)
-            .wrapping_add((carry_mid as u64) << 32);
-        if carry_low {
-            high = high.wrapping_add(1);
+    /// Count leading zeros (not counting sign, just the bits).
+    pub fn leading_zeros(&self) -> u32 {
+        if self.h != 0 {
+            self.h.leading_zeros()
+        } else {
+            64 + self.l.leading_zeros()
         }
+    }
+
+    /// Minimal number of bits needed to hold the two's complement bit
+    /// pattern read as unsigned: 0 for zero, 128 for any negative value.
+    pub fn bits(&self) -> u32 {
+        128 - self.leading_zeros()
+    }
 
-        (high, low)
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.l.count_ones() + self.h.count_ones()
+    }
+
+    /// Number of clear bits.
+    pub fn count_zeros(&self) -> u32 {
+        self.l.count_zeros() + self.h.count_zeros()
+    }
+
+    /// Trailing zero bits. Zero yields the full width (128).
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.l != 0 {
+            self.l.trailing_zeros()
+        } else {
+            64 + self.h.trailing_zeros()
+        }
+    }
+
+    /// Trailing one bits. All-ones yields the full width (128).
+    pub fn trailing_ones(&self) -> u32 {
+        if self.l != u64::MAX {
+            self.l.trailing_ones()
+        } else {
+            64 + self.h.trailing_ones()
+        }
+    }
+}
+
+impl Default for Int128 {
+    fn default() -> Self {
+        Self::ZERO
     }
 }
 
@@ -162,15 +232,7 @@ impl std::ops::Mul for Int128 {
 
     /// Wrapping multiplication. Low 128 bits are identical for signed/unsigned.
     fn mul(self, rhs: Self) -> Self::Output {
-        #[cfg(target_arch = "x86_64")]
-        let (p0_hi, p0_lo) = {
-            let mut hi = 0u64;
-            let lo = unsafe { core::arch::x86_64::_mulx_u64(self.l, rhs.l, &mut hi) };
-            (hi, lo)
-        };
-
-        #[cfg(not(target_arch = "x86_64"))]
-        let (p0_hi, p0_lo) = Self::mul_u64_full(self.l, rhs.l);
+        let (p0_hi, p0_lo) = Uint128::mul_u64_wide(self.l, rhs.l);
 
         let t1_lo = self.l.wrapping_mul(rhs.h);
         let t2_lo = self.h.wrapping_mul(rhs.l);
@@ -215,6 +277,251 @@ impl std::ops::Rem for Int128 {
     }
 }
 
+forward_assign_ops!(Int128);
+forward_ref_binops!(Int128);
+
+// ============================================================================
+// Overflowing, checked, and saturating arithmetic (two's complement)
+// ============================================================================
+
+impl Int128 {
+    /// Addition returning the wrapped result and whether signed overflow
+    /// occurred: both operands share a sign and the result's sign differs.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let result = self + rhs;
+        let overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Subtraction returning the wrapped result and whether signed overflow
+    /// occurred: operands differ in sign and the result's sign differs from
+    /// the minuend's.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let result = self - rhs;
+        let overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Multiplication returning the wrapped result and whether signed
+    /// overflow occurred. Delegates to native i128 for overflow detection.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (result, overflow) = self.to_i128().overflowing_mul(rhs.to_i128());
+        (Self::from_i128(result), overflow)
+    }
+
+    /// Checked addition. Returns `None` on signed overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on signed overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` on signed overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Negation returning the wrapped result and whether it overflowed.
+    /// Only `MIN` overflows, negating to itself.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (-self, self == Self::MIN)
+    }
+
+    /// Checked negation. Returns `None` for `MIN`, whose negation has no
+    /// signed representation.
+    pub fn checked_neg(self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_neg();
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Wrapping negation; `MIN` negates to itself. Same as `-self`.
+    pub fn wrapping_neg(self) -> Self {
+        -self
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or for `MIN / -1`,
+    /// whose quotient overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or for `MIN % -1`,
+    /// matching `i128::checked_rem`.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self % rhs)
+        }
+    }
+
+    /// Wrapping division, like `i128::wrapping_div`: `MIN / -1` wraps to
+    /// `MIN` instead of panicking. Panics if `rhs` is zero.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            Self::MIN
+        } else {
+            self / rhs
+        }
+    }
+
+    /// Wrapping remainder, like `i128::wrapping_rem`: `MIN % -1` is `0`
+    /// instead of panicking. Panics if `rhs` is zero.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            Self::ZERO
+        } else {
+            self % rhs
+        }
+    }
+
+    /// Truncating quotient and remainder, `(self / rhs, self % rhs)`. The
+    /// remainder is recovered as `n - q * d` instead of a second `__modti3`
+    /// call; `|q * d| <= |n|`, so it cannot overflow. Panics like `/`.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let (n, d) = (self.to_i128(), rhs.to_i128());
+        let q = n / d;
+        (Self::from_i128(q), Self::from_i128(n - q * d))
+    }
+
+    /// Euclidean division: the quotient `q` such that
+    /// `self == q * rhs + self.rem_euclid(rhs)` with a non-negative
+    /// remainder. Corrects the truncating quotient by one step when the
+    /// truncating remainder is negative. Panics like `/` for a zero divisor
+    /// and `MIN / -1`.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.is_negative() {
+            if rhs.is_positive() {
+                q - Self::ONE
+            } else {
+                q + Self::ONE
+            }
+        } else {
+            q
+        }
+    }
+
+    /// Euclidean remainder, always in `[0, |rhs|)`. A negative truncating
+    /// remainder is shifted up by `|rhs|`, which cannot overflow since it is
+    /// strictly greater than `-|rhs|`. Panics like `%`.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_negative() {
+            if rhs.is_negative() { r - rhs } else { r + rhs }
+        } else {
+            r
+        }
+    }
+
+    /// Checked Euclidean division. Returns `None` if `rhs` is zero or for
+    /// `MIN / -1`, matching `i128::checked_div_euclid`.
+    pub fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self.div_euclid(rhs))
+        }
+    }
+
+    /// Checked Euclidean remainder. Returns `None` if `rhs` is zero or for
+    /// `MIN % -1`, matching `i128::checked_rem_euclid`.
+    pub fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self.rem_euclid(rhs))
+        }
+    }
+
+    /// Saturating addition. Overflow can only happen when both operands
+    /// share a sign, so clamp toward that sign.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(result) => result,
+            None if self.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating subtraction. Overflow can only happen when the operands
+    /// differ in sign, so clamp toward the minuend's sign.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(result) => result,
+            None if self.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating multiplication. Clamps to `MIN` when the true product is
+    /// negative, `MAX` otherwise.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(result) => result,
+            None if self.is_negative() != rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Wrapping exponentiation by squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    /// Checked exponentiation. Returns `None` on signed overflow.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(base)?;
+            }
+            exp >>= 1;
+            // Skip the final squaring: it is never used and may overflow.
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Absolute difference `|self - other|` as the unsigned type, like
+    /// `i128::abs_diff`. The difference of the bit patterns read as unsigned
+    /// is exact modulo 2^BITS, and the true difference is below 2^BITS.
+    pub fn abs_diff(self, other: Self) -> Uint128 {
+        let (hi, lo) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        Uint128 { l: hi.l, h: hi.h } - Uint128 { l: lo.l, h: lo.h }
+    }
+}
+
 // ============================================================================
 // Comparison (high limb interpreted as signed)
 // ============================================================================
@@ -243,6 +550,8 @@ impl Ord for Int128 {
     }
 }
 
+ord_methods!(Int128);
+
 // ============================================================================
 // Bitwise operations
 // ============================================================================
@@ -344,3 +653,67 @@ impl std::ops::Shr<u32> for Int128 {
         }
     }
 }
+
+forward_bit_assign_ops!(Int128);
+shift_methods!(Int128, 128);
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<i128> for Int128 {
+    fn from(v: i128) -> Self {
+        Self::from_i128(v)
+    }
+}
+
+/// Sign-extends: the high limb is all ones for negative values.
+impl From<i64> for Int128 {
+    fn from(v: i64) -> Self {
+        Self::from_i128(v as i128)
+    }
+}
+
+/// Sign-extends: the high limb is all ones for negative values.
+impl From<Int64> for Int128 {
+    fn from(v: Int64) -> Self {
+        Self::from_i128(v.to_i64() as i128)
+    }
+}
+
+/// Fails unless the value lies in `[Int64::MIN, Int64::MAX]`.
+impl TryFrom<Int128> for Int64 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int128) -> Result<Self, Self::Error> {
+        i64::try_from(v.to_i128())
+            .map(Int64::from_i64)
+            .map_err(|_| TryFromIntError(()))
+    }
+}
+
+/// Fails for negative values.
+impl TryFrom<Int128> for Uint128 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int128) -> Result<Self, Self::Error> {
+        if v.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(Uint128 { l: v.l, h: v.h })
+        }
+    }
+}
+
+/// Fails when the top bit is set (the value exceeds `Int128::MAX`).
+impl TryFrom<Uint128> for Int128 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint128) -> Result<Self, Self::Error> {
+        if v.h >> 63 != 0 {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(Self { l: v.l, h: v.h })
+        }
+    }
+}
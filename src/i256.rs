@@ -3,6 +3,9 @@
 //! Uses two's complement representation. Addition, subtraction, and wrapping
 //! multiplication are bitwise identical to unsigned operations.
 
+use crate::error::{IntErrorKind, ParseIntError, TryFromIntError};
+use crate::i64::Int64;
+use crate::i128::Int128;
 use crate::u256::Uint256;
 use std::cmp::Ordering;
 
@@ -82,10 +85,76 @@ impl Int256 {
         (self.l1 as i128) << 64 | self.l0 as i128
     }
 
+    /// Convert to i128, or `None` if the value lies outside
+    /// `[i128::MIN, i128::MAX]`, i.e. `l2` and `l3` are not both the sign
+    /// extension of `l1`.
+    pub const fn try_to_i128(self) -> Option<i128> {
+        let ext = ((self.l1 as i64) >> 63) as u64;
+        if self.l2 == ext && self.l3 == ext {
+            Some(self.to_i128())
+        } else {
+            None
+        }
+    }
+
+    /// Convert to i128, clamping out-of-range values to `i128::MIN` or
+    /// `i128::MAX` by sign.
+    pub const fn to_i128_saturating(self) -> i128 {
+        match self.try_to_i128() {
+            Some(v) => v,
+            None if (self.l3 as i64) < 0 => i128::MIN,
+            None => i128::MAX,
+        }
+    }
+
+    /// Little-endian two's complement bytes, as for `Uint256::to_le_bytes`.
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        Uint256 { l0: self.l0, l1: self.l1, l2: self.l2, l3: self.l3 }.to_le_bytes()
+    }
+
+    /// Big-endian two's complement bytes.
+    pub const fn to_be_bytes(self) -> [u8; 32] {
+        Uint256 { l0: self.l0, l1: self.l1, l2: self.l2, l3: self.l3 }.to_be_bytes()
+    }
+
+    /// Native-endian two's complement bytes, which is also the struct's own
+    /// memory layout.
+    pub const fn to_ne_bytes(self) -> [u8; 32] {
+        if cfg!(target_endian = "big") { self.to_be_bytes() } else { self.to_le_bytes() }
+    }
+
+    /// Create from little-endian two's complement bytes.
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let u = Uint256::from_le_bytes(bytes);
+        Self::new(u.l0, u.l1, u.l2, u.l3)
+    }
+
+    /// Create from big-endian two's complement bytes.
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let u = Uint256::from_be_bytes(bytes);
+        Self::new(u.l0, u.l1, u.l2, u.l3)
+    }
+
+    /// Create from native-endian two's complement bytes.
+    pub const fn from_ne_bytes(bytes: [u8; 32]) -> Self {
+        if cfg!(target_endian = "big") { Self::from_be_bytes(bytes) } else { Self::from_le_bytes(bytes) }
+    }
+
     pub fn is_zero(&self) -> bool {
         self.l0 == 0 && self.l1 == 0 && self.l2 == 0 && self.l3 == 0
     }
 
+    /// True if the lowest bit is clear. Zero is even.
+    pub const fn is_even(&self) -> bool {
+        self.l0 & 1 == 0
+    }
+
+    /// True if the lowest bit is set. Two's complement preserves parity,
+    /// so this is right for negative values as well.
+    pub const fn is_odd(&self) -> bool {
+        self.l0 & 1 == 1
+    }
+
     pub fn is_negative(&self) -> bool {
         (self.l3 as i64) < 0
     }
@@ -159,6 +228,54 @@ impl Int256 {
             192 + self.l0.leading_zeros()
         }
     }
+
+    /// Minimal number of bits needed to hold the two's complement bit
+    /// pattern read as unsigned: 0 for zero, 256 for any negative value.
+    pub fn bits(&self) -> u32 {
+        256 - self.leading_zeros()
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.l0.count_ones() + self.l1.count_ones() + self.l2.count_ones() + self.l3.count_ones()
+    }
+
+    /// Number of clear bits.
+    pub fn count_zeros(&self) -> u32 {
+        256 - self.count_ones()
+    }
+
+    /// Trailing zero bits. Zero yields the full width (256).
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.l0 != 0 {
+            self.l0.trailing_zeros()
+        } else if self.l1 != 0 {
+            64 + self.l1.trailing_zeros()
+        } else if self.l2 != 0 {
+            128 + self.l2.trailing_zeros()
+        } else {
+            192 + self.l3.trailing_zeros()
+        }
+    }
+
+    /// Trailing one bits. All-ones yields the full width (256).
+    pub fn trailing_ones(&self) -> u32 {
+        if self.l0 != u64::MAX {
+            self.l0.trailing_ones()
+        } else if self.l1 != u64::MAX {
+            64 + self.l1.trailing_ones()
+        } else if self.l2 != u64::MAX {
+            128 + self.l2.trailing_ones()
+        } else {
+            192 + self.l3.trailing_ones()
+        }
+    }
+}
+
+impl Default for Int256 {
+    fn default() -> Self {
+        Self::ZERO
+    }
 }
 
 // ============================================================================
@@ -201,6 +318,13 @@ impl std::ops::Mul for Int256 {
     type Output = Self;
 
     /// Wrapping multiplication. Low 256 bits are identical for signed/unsigned.
+    ///
+    /// Deliberately goes through `Uint256::mul` rather than calling
+    /// `optimal_u256_mul`: that function is `#[inline(never)]` and checks
+    /// for BMI2 at runtime, and paying for the call and the check on every
+    /// multiply costs more than the u128 schoolbook `Uint256::mul` runs.
+    /// Any faster path selected inside `Uint256::mul` is picked up here for
+    /// free; the `asm_i256_mul_x86_64` snapshot pins this to a single call.
     fn mul(self, rhs: Self) -> Self::Output {
         // Delegate to unsigned multiplication - low bits are identical
         let result = self.to_uint256() * rhs.to_uint256();
@@ -231,35 +355,22 @@ impl std::ops::Div for Int256 {
     ///
     /// Strategy: Convert to unsigned magnitudes, divide, fix sign.
     /// This avoids implementing a separate signed division algorithm.
+    /// `MIN`'s magnitude 2^255 fits in Uint256, so only `MIN / -1`, whose
+    /// quotient 2^255 has no signed representation, needs special handling;
+    /// it panics like native `i128`.
     fn div(self, rhs: Self) -> Self::Output {
         if rhs.is_zero() {
             panic!("attempt to divide by zero");
         }
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            panic!("attempt to divide with overflow");
+        }
 
-        // Handle signs
-        let self_neg = self.is_negative();
-        let rhs_neg = rhs.is_negative();
-        let result_neg = self_neg ^ rhs_neg;
-
-        // Get absolute values as unsigned
-        let a = if self_neg {
-            (Self::ZERO - self).to_uint256()
-        } else {
-            self.to_uint256()
-        };
-
-        let b = if rhs_neg {
-            (Self::ZERO - rhs).to_uint256()
-        } else {
-            rhs.to_uint256()
-        };
-
-        // Unsigned division
-        let q = a / b;
+        let q = self.unsigned_abs() / rhs.unsigned_abs();
 
         // Fix sign of result
         let result = Self::from_uint256(q);
-        if result_neg {
+        if self.is_negative() ^ rhs.is_negative() {
             Self::ZERO - result
         } else {
             result
@@ -272,42 +383,310 @@ impl std::ops::Rem for Int256 {
 
     /// Signed remainder. Result has same sign as dividend.
     ///
-    /// Uses the identity: a % b = a - (a / b) * b
+    /// Takes the remainder of the unsigned magnitudes directly from
+    /// `Uint256::div_rem`. `MIN % -1` panics like native `i128`, even though
+    /// the mathematical result is zero.
     fn rem(self, rhs: Self) -> Self::Output {
         if rhs.is_zero() {
             panic!("attempt to calculate remainder with a divisor of zero");
         }
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            panic!("attempt to calculate the remainder with overflow");
+        }
 
-        // Handle signs
-        let self_neg = self.is_negative();
-        let rhs_neg = rhs.is_negative();
+        let (_, r) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
 
-        // Get absolute values as unsigned
-        let a = if self_neg {
-            (Self::ZERO - self).to_uint256()
+        // Result has same sign as dividend
+        let result = Self::from_uint256(r);
+        if self.is_negative() {
+            Self::ZERO - result
         } else {
-            self.to_uint256()
-        };
+            result
+        }
+    }
+}
+
+forward_assign_ops!(Int256);
+forward_ref_binops!(Int256);
+
+// ============================================================================
+// Overflowing, checked, and saturating arithmetic (two's complement)
+// ============================================================================
 
-        let b = if rhs_neg {
-            (Self::ZERO - rhs).to_uint256()
+impl Int256 {
+    /// Addition returning the wrapped result and whether signed overflow
+    /// occurred: both operands share a sign and the result's sign differs.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let result = self + rhs;
+        let overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Subtraction returning the wrapped result and whether signed overflow
+    /// occurred: operands differ in sign and the result's sign differs from
+    /// the minuend's.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let result = self - rhs;
+        let overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Multiplication returning the wrapped result and whether signed
+    /// overflow occurred.
+    ///
+    /// Uses signed semantics like `i128::overflowing_mul`: the flag is set
+    /// when the true product lies outside `[MIN, MAX]`, not when the unsigned
+    /// product of the bit patterns exceeds 256 bits. Detection multiplies the
+    /// unsigned magnitudes, which must stay at most 2^255 for a negative
+    /// result (MIN) and below 2^255 otherwise.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let result_neg = self.is_negative() ^ rhs.is_negative();
+        let (magnitude, wide) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        let fits = if result_neg {
+            magnitude <= Self::MIN.to_uint256()
         } else {
-            rhs.to_uint256()
+            magnitude < Self::MIN.to_uint256()
         };
+        (self * rhs, wide || !fits)
+    }
 
-        // Unsigned division to get quotient
-        let q = a / b;
+    /// Checked addition. Returns `None` on signed overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
 
-        // remainder = |a| - q * |b|
-        let r = a - q * b;
+    /// Checked subtraction. Returns `None` on signed overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
 
-        // Result has same sign as dividend
-        let result = Self::from_uint256(r);
-        if self_neg && !result.is_zero() {
-            Self::ZERO - result
+    /// Checked addition of an unsigned value, like
+    /// `i128::checked_add_unsigned`. Adds the bits as signed; when `rhs` has
+    /// its top bit set it reads as negative, which flips the meaning of the
+    /// signed overflow flag.
+    pub fn checked_add_unsigned(self, rhs: Uint256) -> Option<Self> {
+        let rhs = Self::from_uint256(rhs);
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow != rhs.is_negative() { None } else { Some(result) }
+    }
+
+    /// Checked subtraction of an unsigned value, like
+    /// `i128::checked_sub_unsigned`. Same flag correction as
+    /// `checked_add_unsigned`.
+    pub fn checked_sub_unsigned(self, rhs: Uint256) -> Option<Self> {
+        let rhs = Self::from_uint256(rhs);
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow != rhs.is_negative() { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` on signed overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Negation returning the wrapped result and whether it overflowed.
+    /// Only `MIN` overflows, negating to itself.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (-self, self == Self::MIN)
+    }
+
+    /// Checked negation. Returns `None` for `MIN`, whose negation has no
+    /// signed representation.
+    pub fn checked_neg(self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_neg();
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Wrapping negation; `MIN` negates to itself. Same as `-self`.
+    pub fn wrapping_neg(self) -> Self {
+        -self
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or for `MIN / -1`,
+    /// whose quotient overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
         } else {
-            result
+            Some(self / rhs)
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or for `MIN % -1`,
+    /// matching `i128::checked_rem`.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self % rhs)
+        }
+    }
+
+    /// Wrapping division, like `i128::wrapping_div`: `MIN / -1` wraps to
+    /// `MIN` instead of panicking. Panics if `rhs` is zero.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            Self::MIN
+        } else {
+            self / rhs
+        }
+    }
+
+    /// Wrapping remainder, like `i128::wrapping_rem`: `MIN % -1` is `0`
+    /// instead of panicking. Panics if `rhs` is zero.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            Self::ZERO
+        } else {
+            self % rhs
+        }
+    }
+
+    /// Truncating quotient and remainder, `(self / rhs, self % rhs)`, from a
+    /// single unsigned division of the magnitudes. Panics like `/` for a
+    /// zero divisor and `MIN / -1`.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            panic!("attempt to divide with overflow");
+        }
+        let (q, r) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let (q, r) = (Self::from_uint256(q), Self::from_uint256(r));
+        let q = if self.is_negative() ^ rhs.is_negative() { Self::ZERO - q } else { q };
+        let r = if self.is_negative() { Self::ZERO - r } else { r };
+        (q, r)
+    }
+
+    /// Euclidean division: the quotient `q` such that
+    /// `self == q * rhs + self.rem_euclid(rhs)` with a non-negative
+    /// remainder. Corrects the truncating quotient by one step when the
+    /// truncating remainder is negative. Panics like `/` for a zero divisor
+    /// and `MIN / -1`.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.is_negative() {
+            if rhs.is_positive() {
+                q - Self::ONE
+            } else {
+                q + Self::ONE
+            }
+        } else {
+            q
+        }
+    }
+
+    /// Euclidean remainder, always in `[0, |rhs|)`. A negative truncating
+    /// remainder is shifted up by `|rhs|`, which cannot overflow since it is
+    /// strictly greater than `-|rhs|`. Panics like `%`.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_negative() {
+            if rhs.is_negative() { r - rhs } else { r + rhs }
+        } else {
+            r
+        }
+    }
+
+    /// Checked Euclidean division. Returns `None` if `rhs` is zero or for
+    /// `MIN / -1`, matching `i128::checked_div_euclid`.
+    pub fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self.div_euclid(rhs))
+        }
+    }
+
+    /// Checked Euclidean remainder. Returns `None` if `rhs` is zero or for
+    /// `MIN % -1`, matching `i128::checked_rem_euclid`.
+    pub fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self.rem_euclid(rhs))
+        }
+    }
+
+    /// Saturating addition. Overflow can only happen when both operands
+    /// share a sign, so clamp toward that sign.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(result) => result,
+            None if self.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating subtraction. Overflow can only happen when the operands
+    /// differ in sign, so clamp toward the minuend's sign.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(result) => result,
+            None if self.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating multiplication. Clamps to `MIN` when the true product is
+    /// negative, `MAX` otherwise.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(result) => result,
+            None if self.is_negative() != rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Wrapping exponentiation by squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
         }
+        acc
+    }
+
+    /// Checked exponentiation. Returns `None` on signed overflow.
+    ///
+    /// Raises the magnitude as unsigned; the result is negative only for a
+    /// negative base with an odd exponent, in which case it may reach `MIN`.
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        let magnitude = self.unsigned_abs().checked_pow(exp)?;
+        let negative = self.is_negative() && exp & 1 == 1;
+        let limit = Self::MIN.to_uint256();
+        if negative {
+            (magnitude <= limit).then(|| Self::from_uint256(Uint256::ZERO - magnitude))
+        } else {
+            (magnitude < limit).then(|| Self::from_uint256(magnitude))
+        }
+    }
+
+    /// Absolute difference `|self - other|` as the unsigned type, like
+    /// `i128::abs_diff`. The difference of the bit patterns read as unsigned
+    /// is exact modulo 2^BITS, and the true difference is below 2^BITS.
+    pub fn abs_diff(self, other: Self) -> Uint256 {
+        let (hi, lo) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        hi.to_uint256() - lo.to_uint256()
+    }
+
+    /// Magnitude as unsigned. MIN maps to 2^255, which fits in Uint256.
+    fn unsigned_abs(self) -> Uint256 {
+        self.abs().to_uint256()
     }
 }
 
@@ -349,6 +728,8 @@ impl Ord for Int256 {
     }
 }
 
+ord_methods!(Int256);
+
 // ============================================================================
 // Bitwise operations
 // ============================================================================
@@ -427,9 +808,7 @@ impl std::ops::Shl<u32> for Int256 {
         let limbs = [self.l0, self.l1, self.l2, self.l3];
 
         if bits == 0 {
-            for i in full_limbs..4 {
-                result[i] = limbs[i - full_limbs];
-            }
+            result[full_limbs..].copy_from_slice(&limbs[..4 - full_limbs]);
         } else {
             for i in full_limbs..4 {
                 result[i] = limbs[i - full_limbs] << bits;
@@ -451,7 +830,9 @@ impl std::ops::Shl<u32> for Int256 {
 impl std::ops::Shr<u32> for Int256 {
     type Output = Self;
 
-    /// Arithmetic right shift: fills with sign bit.
+    /// Arithmetic right shift: fills with the sign bit, like `>>` on `i128`.
+    /// Shifting by 256 or more gives `-1` for negative values and `0`
+    /// otherwise. See [`Int256::logical_shr`] for a zero-filling shift.
     fn shr(self, n: u32) -> Self::Output {
         if n >= 256 {
             return if self.is_negative() {
@@ -472,9 +853,7 @@ impl std::ops::Shr<u32> for Int256 {
         let limbs = [self.l0, self.l1, self.l2, self.l3];
 
         if bits == 0 {
-            for i in 0..(4 - full_limbs) {
-                result[i] = limbs[i + full_limbs];
-            }
+            result[..4 - full_limbs].copy_from_slice(&limbs[full_limbs..]);
         } else {
             for i in 0..(4 - full_limbs) {
                 result[i] = limbs[i + full_limbs] >> bits;
@@ -495,3 +874,272 @@ impl std::ops::Shr<u32> for Int256 {
         }
     }
 }
+
+forward_bit_assign_ops!(Int256);
+forward_shift_ops!(Int256);
+shift_methods!(Int256, 256);
+
+impl Int256 {
+    /// Logical right shift: fills with zeros regardless of sign, the same as
+    /// shifting the two's complement bits as a `Uint256`. Shifting by 256 or
+    /// more gives zero.
+    pub fn logical_shr(self, n: u32) -> Self {
+        Self::from_uint256(self.to_uint256() >> n)
+    }
+}
+
+// ============================================================================
+// Constant-time operations
+// ============================================================================
+
+impl Int256 {
+    /// Branch-free conditional negation: `-self` if `cond`, else `self`.
+    ///
+    /// Two's complement negation is `!x + 1`. The condition is widened to an
+    /// all-ones or all-zeros mask, XORed into every limb, and added back as
+    /// the low carry, so both cases run the same instructions. Like `Neg`,
+    /// `MIN` negates to itself.
+    pub fn conditional_negate(self, cond: bool) -> Self {
+        let bit = cond as u64;
+        let mask = bit.wrapping_neg();
+        let (l0, c0) = (self.l0 ^ mask).overflowing_add(bit);
+        let (l1, c1) = (self.l1 ^ mask).overflowing_add(c0 as u64);
+        let (l2, c2) = (self.l2 ^ mask).overflowing_add(c1 as u64);
+        let l3 = (self.l3 ^ mask).wrapping_add(c2 as u64);
+        Self::new(l0, l1, l2, l3)
+    }
+}
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+impl std::fmt::Display for Int256 {
+    /// Decimal formatting with a leading `-` for negative values.
+    ///
+    /// The magnitude is the wrapping negation of the bit pattern read as
+    /// unsigned. This also covers MIN, whose magnitude 2^255 has no positive
+    /// Int256 representation but fits in Uint256.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let magnitude = if self.is_negative() {
+            Uint256::ZERO - self.to_uint256()
+        } else {
+            self.to_uint256()
+        };
+        f.pad_integral(!self.is_negative(), "", &magnitude.to_string())
+    }
+}
+
+// Radix formats print the raw two's complement bit pattern, like native i128.
+
+impl std::fmt::LowerHex for Int256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&self.to_uint256(), f)
+    }
+}
+
+impl std::fmt::UpperHex for Int256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::UpperHex::fmt(&self.to_uint256(), f)
+    }
+}
+
+impl std::fmt::Binary for Int256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Binary::fmt(&self.to_uint256(), f)
+    }
+}
+
+impl std::fmt::Octal for Int256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Octal::fmt(&self.to_uint256(), f)
+    }
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+impl Int256 {
+    /// Parse an optional `+`/`-` sign followed by a magnitude in `radix`.
+    ///
+    /// Values outside `[MIN, MAX]` are rejected with `PosOverflow` or
+    /// `NegOverflow` rather than wrapping. A magnitude of exactly 2^255 is
+    /// accepted when negative and maps to MIN.
+    pub(crate) fn parse_signed(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        if s.is_empty() {
+            return Err(ParseIntError::new(IntErrorKind::Empty));
+        }
+
+        let (negative, digits) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let overflow_kind = if negative {
+            IntErrorKind::NegOverflow
+        } else {
+            IntErrorKind::PosOverflow
+        };
+        let magnitude = Uint256::parse_digits(digits, radix).map_err(|e| match e.kind() {
+            IntErrorKind::PosOverflow => ParseIntError::new(overflow_kind),
+            _ => e,
+        })?;
+
+        let limit = Self::MIN.to_uint256();
+        if negative && magnitude <= limit {
+            Ok(Self::from_uint256(Uint256::ZERO - magnitude))
+        } else if !negative && magnitude < limit {
+            Ok(Self::from_uint256(magnitude))
+        } else {
+            Err(ParseIntError::new(overflow_kind))
+        }
+    }
+}
+
+impl Int256 {
+    /// Parse a string in the given radix (2..=36), like `i128::from_str_radix`.
+    ///
+    /// The sign applies to the magnitude, so `"-ff"` in radix 16 is -255.
+    /// This is not the inverse of the hex formatting, which prints the two's
+    /// complement bits: `"ff..ff"` overflows rather than giving -1, as with
+    /// the native types. The radix prefixes accepted by
+    /// `Uint256::from_str_radix` may follow the sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in 2..=36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix_int: must lie in the range `[2, 36]` - found {radix}"
+        );
+        Self::parse_signed(s, radix)
+    }
+
+    /// Parse an optional sign followed by a magnitude whose prefix picks the
+    /// radix: `0x`/`0X` for 16, `0o`/`0O` for 8, `0b`/`0B` for 2, and
+    /// decimal with no prefix. `"-0xFF"` is -255.
+    ///
+    /// As with `from_str_radix` the sign applies to the magnitude, and
+    /// values outside `[MIN, MAX]` fail with `PosOverflow` or `NegOverflow`.
+    pub fn from_str_prefixed(s: &str) -> Result<Self, ParseIntError> {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let radix = match unsigned.get(..2) {
+            Some("0x" | "0X") => 16,
+            Some("0o" | "0O") => 8,
+            Some("0b" | "0B") => 2,
+            _ => 10,
+        };
+        Self::parse_signed(s, radix)
+    }
+}
+
+impl std::str::FromStr for Int256 {
+    type Err = ParseIntError;
+
+    /// Parse a decimal string with an optional leading `+` or `-`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_signed(s, 10)
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+/// Sign-extends: the upper limbs are all ones for negative values.
+impl From<i128> for Int256 {
+    fn from(v: i128) -> Self {
+        Self::from_i128(v)
+    }
+}
+
+/// Sign-extends: the upper limbs are all ones for negative values.
+impl From<i64> for Int256 {
+    fn from(v: i64) -> Self {
+        Self::from_i128(v as i128)
+    }
+}
+
+impl From<isize> for Int256 {
+    fn from(v: isize) -> Self {
+        Self::from_i128(v as i128)
+    }
+}
+
+/// Fails unless the value lies in `[isize::MIN, isize::MAX]` on this target.
+impl TryFrom<Int256> for isize {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int256) -> Result<Self, Self::Error> {
+        v.try_to_i128()
+            .and_then(|n| isize::try_from(n).ok())
+            .ok_or(TryFromIntError(()))
+    }
+}
+
+/// Sign-extends: the upper limbs are all ones for negative values.
+impl From<Int64> for Int256 {
+    fn from(v: Int64) -> Self {
+        Self::from_i128(v.to_i64() as i128)
+    }
+}
+
+/// Sign-extends: the upper limbs are all ones for negative values.
+impl From<Int128> for Int256 {
+    fn from(v: Int128) -> Self {
+        Self::from_i128(v.to_i128())
+    }
+}
+
+/// Fails unless the value lies in `[Int128::MIN, Int128::MAX]`, i.e. the
+/// upper limbs are the sign extension of `l1`.
+impl TryFrom<Int256> for Int128 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int256) -> Result<Self, Self::Error> {
+        let narrowed = v.to_i128();
+        if Int256::from_i128(narrowed) == v {
+            Ok(Int128::from_i128(narrowed))
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+/// Fails unless the value lies in `[Int64::MIN, Int64::MAX]`.
+impl TryFrom<Int256> for Int64 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int256) -> Result<Self, Self::Error> {
+        Int64::try_from(Int128::try_from(v)?)
+    }
+}
+
+/// Fails for negative values.
+impl TryFrom<Int256> for Uint256 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int256) -> Result<Self, Self::Error> {
+        if v.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(v.to_uint256())
+        }
+    }
+}
+
+/// Fails when the top bit is set (the value exceeds `Int256::MAX`).
+impl TryFrom<Uint256> for Int256 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint256) -> Result<Self, Self::Error> {
+        if v.l3 >> 63 != 0 {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(Self::from_uint256(v))
+        }
+    }
+}
@@ -0,0 +1,443 @@
+//! 512-bit signed integer implemented as eight 64-bit limbs.
+//!
+//! Uses two's complement representation on top of `Uint512`, the same way
+//! `Int256` builds on `Uint256`.
+
+use crate::error::TryFromIntError;
+use crate::i256::Int256;
+use crate::u512::Uint512;
+use std::cmp::Ordering;
+
+/// 512-bit signed integer stored as eight 64-bit limbs.
+///
+/// Uses two's complement representation. The high limb's MSB is the sign bit.
+/// Field order matches native memory layout for optimal codegen.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_endian = "little")]
+pub struct Int512 {
+    pub l0: u64, // bits 0-63 (lowest address)
+    pub l1: u64, // bits 64-127
+    pub l2: u64, // bits 128-191
+    pub l3: u64, // bits 192-255
+    pub l4: u64, // bits 256-319
+    pub l5: u64, // bits 320-383
+    pub l6: u64, // bits 384-447
+    pub l7: u64, // bits 448-511, MSB is sign bit (highest address)
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_endian = "big")]
+pub struct Int512 {
+    pub l7: u64, // bits 448-511, MSB is sign bit (lowest address)
+    pub l6: u64, // bits 384-447
+    pub l5: u64, // bits 320-383
+    pub l4: u64, // bits 256-319
+    pub l3: u64, // bits 192-255
+    pub l2: u64, // bits 128-191
+    pub l1: u64, // bits 64-127
+    pub l0: u64, // bits 0-63 (highest address)
+}
+
+impl Int512 {
+    pub const ZERO: Self = Self::from_uint512(Uint512::ZERO);
+    pub const ONE: Self = Self::from_uint512(Uint512::ONE);
+    pub const NEG_ONE: Self = Self::from_uint512(Uint512::MAX);
+    pub const MIN: Self = Self::from_uint512(Uint512::from_array([
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0x8000_0000_0000_0000,
+    ]));
+    pub const MAX: Self = Self::from_uint512(Uint512::from_array([
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        0x7FFF_FFFF_FFFF_FFFF,
+    ]));
+
+    pub fn is_zero(&self) -> bool {
+        self.to_uint512().is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        (self.l7 as i64) < 0
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    /// Absolute value. Note: MIN.abs() overflows (returns MIN).
+    pub fn abs(&self) -> Self {
+        if self.is_negative() { -*self } else { *self }
+    }
+
+    /// Checked absolute value. Returns None for MIN.
+    pub fn checked_abs(&self) -> Option<Self> {
+        if *self == Self::MIN {
+            None
+        } else {
+            Some(self.abs())
+        }
+    }
+
+    /// Convert to unsigned, interpreting bits directly.
+    pub const fn to_uint512(&self) -> Uint512 {
+        Uint512::from_array([
+            self.l0, self.l1, self.l2, self.l3, self.l4, self.l5, self.l6, self.l7,
+        ])
+    }
+
+    /// Create from unsigned, interpreting bits directly.
+    pub const fn from_uint512(u: Uint512) -> Self {
+        Self {
+            l0: u.l0,
+            l1: u.l1,
+            l2: u.l2,
+            l3: u.l3,
+            l4: u.l4,
+            l5: u.l5,
+            l6: u.l6,
+            l7: u.l7,
+        }
+    }
+
+    /// Magnitude as unsigned. MIN maps to 2^511, which fits in Uint512.
+    fn unsigned_abs(self) -> Uint512 {
+        self.abs().to_uint512()
+    }
+}
+
+impl Default for Int512 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+// ============================================================================
+// Addition, subtraction, multiplication (identical to unsigned)
+// ============================================================================
+
+impl std::ops::Add for Int512 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_uint512(self.to_uint512() + rhs.to_uint512())
+    }
+}
+
+impl std::ops::Sub for Int512 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_uint512(self.to_uint512() - rhs.to_uint512())
+    }
+}
+
+impl std::ops::Mul for Int512 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_uint512(self.to_uint512() * rhs.to_uint512())
+    }
+}
+
+impl std::ops::Neg for Int512 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+// ============================================================================
+// Division (requires sign handling)
+// ============================================================================
+
+impl std::ops::Div for Int512 {
+    type Output = Self;
+
+    /// Signed division with truncation toward zero, on the unsigned
+    /// magnitudes. `MIN / -1` panics like native `i128`.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            panic!("attempt to divide with overflow");
+        }
+
+        let (q, _) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let result = Self::from_uint512(q);
+        if self.is_negative() ^ rhs.is_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+}
+
+impl std::ops::Rem for Int512 {
+    type Output = Self;
+
+    /// Signed remainder. Result has same sign as dividend.
+    fn rem(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            panic!("attempt to calculate the remainder with overflow");
+        }
+
+        let (_, r) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let result = Self::from_uint512(r);
+        if self.is_negative() { -result } else { result }
+    }
+}
+
+forward_assign_ops!(Int512);
+forward_ref_binops!(Int512);
+
+// ============================================================================
+// Overflowing and checked arithmetic (two's complement)
+// ============================================================================
+
+impl Int512 {
+    /// Addition returning the wrapped result and whether signed overflow
+    /// occurred: both operands share a sign and the result's sign differs.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let result = self + rhs;
+        let overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Subtraction returning the wrapped result and whether signed overflow
+    /// occurred: operands differ in sign and the result's sign differs from
+    /// the minuend's.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let result = self - rhs;
+        let overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Multiplication returning the wrapped result and whether the true
+    /// product lies outside `[MIN, MAX]`.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let result_neg = self.is_negative() ^ rhs.is_negative();
+        let (magnitude, wide) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        let fits = if result_neg {
+            magnitude <= Self::MIN.to_uint512()
+        } else {
+            magnitude < Self::MIN.to_uint512()
+        };
+        (self * rhs, wide || !fits)
+    }
+
+    /// Checked addition. Returns `None` on signed overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on signed overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` on signed overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or for `MIN / -1`.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or for `MIN % -1`.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self % rhs)
+        }
+    }
+}
+
+// ============================================================================
+// Comparison (high limb interpreted as signed)
+// ============================================================================
+
+impl PartialEq for Int512 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_uint512() == other.to_uint512()
+    }
+}
+
+impl Eq for Int512 {}
+
+impl PartialOrd for Int512 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Int512 {
+    /// Signed comparison: flipping the sign bit maps two's complement order
+    /// onto unsigned order.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let bias = Self::MIN.to_uint512();
+        (self.to_uint512() ^ bias).cmp(&(other.to_uint512() ^ bias))
+    }
+}
+
+// ============================================================================
+// Bitwise operations and shifts
+// ============================================================================
+
+impl std::ops::Not for Int512 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::from_uint512(!self.to_uint512())
+    }
+}
+
+impl std::ops::BitAnd for Int512 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::from_uint512(self.to_uint512() & rhs.to_uint512())
+    }
+}
+
+impl std::ops::BitOr for Int512 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::from_uint512(self.to_uint512() | rhs.to_uint512())
+    }
+}
+
+impl std::ops::BitXor for Int512 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self::from_uint512(self.to_uint512() ^ rhs.to_uint512())
+    }
+}
+
+impl std::ops::Shl<u32> for Int512 {
+    type Output = Self;
+
+    fn shl(self, n: u32) -> Self::Output {
+        Self::from_uint512(self.to_uint512() << n)
+    }
+}
+
+impl std::ops::Shr<u32> for Int512 {
+    type Output = Self;
+
+    /// Arithmetic right shift: fills with the sign bit. For negative values
+    /// this is `!(!x >> n)` with a logical shift.
+    fn shr(self, n: u32) -> Self::Output {
+        if self.is_negative() {
+            !Self::from_uint512(!self.to_uint512() >> n)
+        } else {
+            Self::from_uint512(self.to_uint512() >> n)
+        }
+    }
+}
+
+forward_bit_assign_ops!(Int512);
+shift_methods!(Int512, 512);
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+impl std::fmt::Display for Int512 {
+    /// Decimal formatting with a leading `-` for negative values. MIN's
+    /// magnitude 2^511 fits in Uint512.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.is_negative(), "", &self.unsigned_abs().to_string())
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<i128> for Int512 {
+    fn from(v: i128) -> Self {
+        Self::from(Int256::from_i128(v))
+    }
+}
+
+/// Sign-extends into the upper four limbs.
+impl From<Int256> for Int512 {
+    fn from(v: Int256) -> Self {
+        let ext = if v.is_negative() { u64::MAX } else { 0 };
+        Self::from_uint512(Uint512::from_array([
+            v.l0, v.l1, v.l2, v.l3, ext, ext, ext, ext,
+        ]))
+    }
+}
+
+/// Fails unless the value lies in `[Int256::MIN, Int256::MAX]`.
+impl TryFrom<Int512> for Int256 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int512) -> Result<Self, Self::Error> {
+        let narrowed = Int256::new(v.l0, v.l1, v.l2, v.l3);
+        if Int512::from(narrowed) == v {
+            Ok(narrowed)
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+/// Fails for negative values.
+impl TryFrom<Int512> for Uint512 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int512) -> Result<Self, Self::Error> {
+        if v.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(v.to_uint512())
+        }
+    }
+}
+
+/// Fails when the top bit is set (the value exceeds `Int512::MAX`).
+impl TryFrom<Uint512> for Int512 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint512) -> Result<Self, Self::Error> {
+        if v.l7 >> 63 != 0 {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(Self::from_uint512(v))
+        }
+    }
+}
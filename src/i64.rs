@@ -3,6 +3,8 @@
 //! Uses two's complement representation. Addition, subtraction, and wrapping
 //! multiplication are bitwise identical to unsigned operations.
 
+use crate::error::TryFromIntError;
+use crate::u64::Uint64;
 use std::cmp::Ordering;
 
 /// 64-bit signed integer stored as two 32-bit limbs.
@@ -54,10 +56,41 @@ impl Int64 {
         (self.h as i64) << 32 | self.l as i64
     }
 
+    /// Little-endian byte representation, identical to `i64::to_le_bytes`.
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.to_i64().to_le_bytes()
+    }
+
+    /// Big-endian byte representation, identical to `i64::to_be_bytes`.
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.to_i64().to_be_bytes()
+    }
+
+    /// Create from little-endian bytes, identical to `i64::from_le_bytes`.
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_i64(i64::from_le_bytes(bytes))
+    }
+
+    /// Create from big-endian bytes, identical to `i64::from_be_bytes`.
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_i64(i64::from_be_bytes(bytes))
+    }
+
     pub fn is_zero(&self) -> bool {
         self.l == 0 && self.h == 0
     }
 
+    /// True if the lowest bit is clear. Zero is even.
+    pub const fn is_even(&self) -> bool {
+        self.l & 1 == 0
+    }
+
+    /// True if the lowest bit is set. Two's complement preserves parity,
+    /// so this is right for negative values as well.
+    pub const fn is_odd(&self) -> bool {
+        self.l & 1 == 1
+    }
+
     pub fn is_negative(&self) -> bool {
         (self.h as i32) < 0
     }
@@ -98,6 +131,55 @@ impl Int64 {
             Some(self.abs())
         }
     }
+
+    /// Count leading zeros (not counting sign, just the bits).
+    pub fn leading_zeros(&self) -> u32 {
+        if self.h != 0 {
+            self.h.leading_zeros()
+        } else {
+            32 + self.l.leading_zeros()
+        }
+    }
+
+    /// Minimal number of bits needed to hold the two's complement bit
+    /// pattern read as unsigned: 0 for zero, 64 for any negative value.
+    pub fn bits(&self) -> u32 {
+        64 - self.leading_zeros()
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.l.count_ones() + self.h.count_ones()
+    }
+
+    /// Number of clear bits.
+    pub fn count_zeros(&self) -> u32 {
+        self.l.count_zeros() + self.h.count_zeros()
+    }
+
+    /// Trailing zero bits. Zero yields the full width (64).
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.l != 0 {
+            self.l.trailing_zeros()
+        } else {
+            32 + self.h.trailing_zeros()
+        }
+    }
+
+    /// Trailing one bits. All-ones yields the full width (64).
+    pub fn trailing_ones(&self) -> u32 {
+        if self.l != u32::MAX {
+            self.l.trailing_ones()
+        } else {
+            32 + self.h.trailing_ones()
+        }
+    }
+}
+
+impl Default for Int64 {
+    fn default() -> Self {
+        Self::ZERO
+    }
 }
 
 // ============================================================================
@@ -184,6 +266,252 @@ impl std::ops::Rem for Int64 {
     }
 }
 
+forward_assign_ops!(Int64);
+forward_ref_binops!(Int64);
+
+// ============================================================================
+// Overflowing, checked, and saturating arithmetic (two's complement)
+// ============================================================================
+
+impl Int64 {
+    /// Addition returning the wrapped result and whether signed overflow
+    /// occurred: both operands share a sign and the result's sign differs.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let result = self + rhs;
+        let overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Subtraction returning the wrapped result and whether signed overflow
+    /// occurred: operands differ in sign and the result's sign differs from
+    /// the minuend's.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let result = self - rhs;
+        let overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Multiplication returning the wrapped result and whether signed
+    /// overflow occurred. Delegates to native i64 for overflow detection.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (result, overflow) = self.to_i64().overflowing_mul(rhs.to_i64());
+        (Self::from_i64(result), overflow)
+    }
+
+    /// Checked addition. Returns `None` on signed overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on signed overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` on signed overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Negation returning the wrapped result and whether it overflowed.
+    /// Only `MIN` overflows, negating to itself.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (-self, self == Self::MIN)
+    }
+
+    /// Checked negation. Returns `None` for `MIN`, whose negation has no
+    /// signed representation.
+    pub fn checked_neg(self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_neg();
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Wrapping negation; `MIN` negates to itself. Same as `-self`.
+    pub fn wrapping_neg(self) -> Self {
+        -self
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or for `MIN / -1`,
+    /// whose quotient overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or for `MIN % -1`,
+    /// matching `i128::checked_rem`.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self % rhs)
+        }
+    }
+
+    /// Wrapping division, like `i128::wrapping_div`: `MIN / -1` wraps to
+    /// `MIN` instead of panicking. Panics if `rhs` is zero.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            Self::MIN
+        } else {
+            self / rhs
+        }
+    }
+
+    /// Wrapping remainder, like `i128::wrapping_rem`: `MIN % -1` is `0`
+    /// instead of panicking. Panics if `rhs` is zero.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            Self::ZERO
+        } else {
+            self % rhs
+        }
+    }
+
+    /// Truncating quotient and remainder, `(self / rhs, self % rhs)`. The
+    /// remainder is recovered as `n - q * d`, so 32-bit targets make one
+    /// `__divdi3` call rather than adding `__moddi3`; `|q * d| <= |n|`, so it
+    /// cannot overflow. Panics like `/`.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let (n, d) = (self.to_i64(), rhs.to_i64());
+        let q = n / d;
+        (Self::from_i64(q), Self::from_i64(n - q * d))
+    }
+
+    /// Euclidean division: the quotient `q` such that
+    /// `self == q * rhs + self.rem_euclid(rhs)` with a non-negative
+    /// remainder. Corrects the truncating quotient by one step when the
+    /// truncating remainder is negative. Panics like `/` for a zero divisor
+    /// and `MIN / -1`.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.is_negative() {
+            if rhs.is_positive() {
+                q - Self::ONE
+            } else {
+                q + Self::ONE
+            }
+        } else {
+            q
+        }
+    }
+
+    /// Euclidean remainder, always in `[0, |rhs|)`. A negative truncating
+    /// remainder is shifted up by `|rhs|`, which cannot overflow since it is
+    /// strictly greater than `-|rhs|`. Panics like `%`.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_negative() {
+            if rhs.is_negative() { r - rhs } else { r + rhs }
+        } else {
+            r
+        }
+    }
+
+    /// Checked Euclidean division. Returns `None` if `rhs` is zero or for
+    /// `MIN / -1`, matching `i128::checked_div_euclid`.
+    pub fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self.div_euclid(rhs))
+        }
+    }
+
+    /// Checked Euclidean remainder. Returns `None` if `rhs` is zero or for
+    /// `MIN % -1`, matching `i128::checked_rem_euclid`.
+    pub fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::NEG_ONE) {
+            None
+        } else {
+            Some(self.rem_euclid(rhs))
+        }
+    }
+
+    /// Saturating addition. Overflow can only happen when both operands
+    /// share a sign, so clamp toward that sign.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(result) => result,
+            None if self.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating subtraction. Overflow can only happen when the operands
+    /// differ in sign, so clamp toward the minuend's sign.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(result) => result,
+            None if self.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating multiplication. Clamps to `MIN` when the true product is
+    /// negative, `MAX` otherwise.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(result) => result,
+            None if self.is_negative() != rhs.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Wrapping exponentiation by squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    /// Checked exponentiation. Returns `None` on signed overflow.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(base)?;
+            }
+            exp >>= 1;
+            // Skip the final squaring: it is never used and may overflow.
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Absolute difference `|self - other|` as the unsigned type, like
+    /// `i128::abs_diff`. The difference of the bit patterns read as unsigned
+    /// is exact modulo 2^BITS, and the true difference is below 2^BITS.
+    pub fn abs_diff(self, other: Self) -> Uint64 {
+        let (hi, lo) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        Uint64 { l: hi.l, h: hi.h } - Uint64 { l: lo.l, h: lo.h }
+    }
+}
+
 // ============================================================================
 // Comparison (high limb interpreted as signed)
 // ============================================================================
@@ -212,6 +540,8 @@ impl Ord for Int64 {
     }
 }
 
+ord_methods!(Int64);
+
 // ============================================================================
 // Bitwise operations
 // ============================================================================
@@ -314,3 +644,36 @@ impl std::ops::Shr<u32> for Int64 {
         }
     }
 }
+
+forward_bit_assign_ops!(Int64);
+shift_methods!(Int64, 64);
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+/// Fails for negative values.
+impl TryFrom<Int64> for Uint64 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int64) -> Result<Self, Self::Error> {
+        if v.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(Uint64::new(v.l, v.h))
+        }
+    }
+}
+
+/// Fails when the top bit is set (the value exceeds `Int64::MAX`).
+impl TryFrom<Uint64> for Int64 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint64) -> Result<Self, Self::Error> {
+        if v.h >> 31 != 0 {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(Self { l: v.l, h: v.h })
+        }
+    }
+}
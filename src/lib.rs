@@ -1,21 +1,43 @@
 #![feature(bigint_helper_methods)]
 
+#[macro_use]
+mod macros;
+
+mod barrett;
+mod biguint;
+mod error;
 mod i128;
 mod i256;
+mod i512;
 mod i64;
+mod montgomery;
 mod u128;
 mod u256;
+mod u512;
 mod u64;
 
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "subtle")]
+mod constant_time;
+
 #[cfg(test)]
 mod tests;
 
+pub use barrett::Barrett256;
+pub use biguint::BigUint;
+pub use error::{IntErrorKind, ParseIntError, TryFromIntError, TryFromSliceError};
 pub use i64::Int64;
 pub use i128::Int128;
 pub use i256::Int256;
+pub use i512::Int512;
+pub use montgomery::Montgomery256;
 pub use u64::Uint64;
 pub use u128::Uint128;
 pub use u256::Uint256;
+pub use u512::Uint512;
 #[cfg(target_arch = "x86_64")]
 pub use u256::optimal_u256_mul;
 
@@ -0,0 +1,282 @@
+//! Helper macros for trait impls that are identical across the integer types.
+//!
+//! The types are all `Copy`, so these only forward to the by-value operators.
+
+/// Compound arithmetic assignment (`+=`, `-=`, `*=`, `/=`, `%=`) for a type
+/// that already implements `Add`, `Sub`, `Mul`, `Div` and `Rem`.
+macro_rules! forward_assign_ops {
+    ($t:ty) => {
+        impl std::ops::AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl std::ops::SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl std::ops::MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl std::ops::DivAssign for $t {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl std::ops::RemAssign for $t {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+    };
+}
+
+/// Compound bitwise and shift assignment (`&=`, `|=`, `^=`, `<<=`, `>>=`) for
+/// a type that already implements `BitAnd`, `BitOr`, `BitXor`, `Shl<u32>`
+/// and `Shr<u32>`.
+macro_rules! forward_bit_assign_ops {
+    ($t:ty) => {
+        impl std::ops::BitAndAssign for $t {
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl std::ops::BitOrAssign for $t {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl std::ops::BitXorAssign for $t {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl std::ops::ShlAssign<u32> for $t {
+            fn shl_assign(&mut self, n: u32) {
+                *self = *self << n;
+            }
+        }
+
+        impl std::ops::ShrAssign<u32> for $t {
+            fn shr_assign(&mut self, n: u32) {
+                *self = *self >> n;
+            }
+        }
+    };
+}
+
+/// `&T op T`, `T op &T` and `&T op &T` for the listed binary operators,
+/// dereferencing and delegating to `T op T`.
+macro_rules! forward_ref_binops {
+    ($t:ty) => {
+        forward_ref_binops!(
+            $t,
+            Add add,
+            Sub sub,
+            Mul mul,
+            Div div,
+            Rem rem,
+            BitAnd bitand,
+            BitOr bitor,
+            BitXor bitxor
+        );
+    };
+    ($t:ty, $($imp:ident $method:ident),+) => {
+        $(
+            impl std::ops::$imp<&$t> for $t {
+                type Output = $t;
+
+                fn $method(self, rhs: &$t) -> $t {
+                    std::ops::$imp::$method(self, *rhs)
+                }
+            }
+
+            impl std::ops::$imp<$t> for &$t {
+                type Output = $t;
+
+                fn $method(self, rhs: $t) -> $t {
+                    std::ops::$imp::$method(*self, rhs)
+                }
+            }
+
+            impl std::ops::$imp<&$t> for &$t {
+                type Output = $t;
+
+                fn $method(self, rhs: &$t) -> $t {
+                    std::ops::$imp::$method(*self, *rhs)
+                }
+            }
+        )+
+    };
+}
+
+/// Shift methods with the standard library's handling of oversized shift
+/// amounts, for a type of `$bits` bits with `Shl<u32>` and `Shr<u32>`.
+///
+/// The operators themselves saturate: shifting by `$bits` or more yields
+/// zero (or all sign bits for a signed `>>`). These methods instead mask the
+/// amount to `n % $bits`, as native `wrapping_shl`/`wrapping_shr` do, or
+/// reject it with `None`, as native `checked_shl`/`checked_shr` do.
+macro_rules! shift_methods {
+    ($t:ty, $bits:expr) => {
+        impl $t {
+            /// Left shift by `n % BITS`, so the shift never discards the
+            /// whole value.
+            pub fn wrapping_shl(self, n: u32) -> Self {
+                self << (n % $bits)
+            }
+
+            /// Right shift by `n % BITS`, so the shift never discards the
+            /// whole value.
+            pub fn wrapping_shr(self, n: u32) -> Self {
+                self >> (n % $bits)
+            }
+
+            /// Left shift, or `None` if `n >= BITS`.
+            pub fn checked_shl(self, n: u32) -> Option<Self> {
+                (n < $bits).then(|| self << n)
+            }
+
+            /// Right shift, or `None` if `n >= BITS`.
+            pub fn checked_shr(self, n: u32) -> Option<Self> {
+                (n < $bits).then(|| self >> n)
+            }
+        }
+    };
+}
+
+/// `Shl` and `Shr` for every primitive integer shift amount, delegating to
+/// the `u32` impls like native integers accept any integer RHS.
+///
+/// Amounts beyond `u32::MAX` saturate, which the `u32` impls already treat
+/// as shifting everything out. A negative amount panics with the message
+/// native debug builds use.
+macro_rules! forward_shift_ops {
+    ($t:ty) => {
+        forward_shift_ops!($t; u8 u16 u64 u128 usize; i8 i16 i32 i64 i128 isize);
+    };
+    ($t:ty; $($u:ty)*; $($i:ty)*) => {
+        $(
+            impl std::ops::Shl<$u> for $t {
+                type Output = $t;
+
+                fn shl(self, n: $u) -> $t {
+                    self << (n as u128).min(u32::MAX as u128) as u32
+                }
+            }
+
+            impl std::ops::Shr<$u> for $t {
+                type Output = $t;
+
+                fn shr(self, n: $u) -> $t {
+                    self >> (n as u128).min(u32::MAX as u128) as u32
+                }
+            }
+        )*
+        $(
+            impl std::ops::Shl<$i> for $t {
+                type Output = $t;
+
+                fn shl(self, n: $i) -> $t {
+                    if n < 0 {
+                        panic!("attempt to shift left with overflow");
+                    }
+                    self << (n as i128).min(u32::MAX as i128) as u32
+                }
+            }
+
+            impl std::ops::Shr<$i> for $t {
+                type Output = $t;
+
+                fn shr(self, n: $i) -> $t {
+                    if n < 0 {
+                        panic!("attempt to shift right with overflow");
+                    }
+                    self >> (n as i128).min(u32::MAX as i128) as u32
+                }
+            }
+        )*
+    };
+}
+
+/// `PartialEq` and `PartialOrd` in both directions between an unsigned type
+/// and a narrower one, so mixed-width comparisons need no manual widening.
+///
+/// The wider value is narrowed with its `TryFrom` impl, which fails exactly
+/// when its high limbs are non-zero; in that case it is the greater one.
+macro_rules! widening_cmp {
+    ($wide:ty, $narrow:ty) => {
+        impl PartialEq<$narrow> for $wide {
+            fn eq(&self, other: &$narrow) -> bool {
+                <$narrow>::try_from(*self).is_ok_and(|n| n == *other)
+            }
+        }
+
+        impl PartialEq<$wide> for $narrow {
+            fn eq(&self, other: &$wide) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$narrow> for $wide {
+            fn partial_cmp(&self, other: &$narrow) -> Option<std::cmp::Ordering> {
+                match <$narrow>::try_from(*self) {
+                    Ok(n) => Some(n.cmp(other)),
+                    Err(_) => Some(std::cmp::Ordering::Greater),
+                }
+            }
+        }
+
+        impl PartialOrd<$wide> for $narrow {
+            fn partial_cmp(&self, other: &$wide) -> Option<std::cmp::Ordering> {
+                other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+            }
+        }
+    };
+}
+
+/// Inherent `min`, `max` and `clamp`, so they are found without importing
+/// `Ord` and show up in the type's own docs.
+///
+/// `clamp` only checks `lo <= hi` with a debug assertion, unlike `Ord::clamp`
+/// which always panics; release builds return `lo` or `hi` for inverted
+/// bounds without checking.
+macro_rules! ord_methods {
+    ($t:ty) => {
+        impl $t {
+            /// The smaller of the two values, `self` if they are equal.
+            pub fn min(self, other: Self) -> Self {
+                if other < self { other } else { self }
+            }
+
+            /// The larger of the two values, `other` if they are equal.
+            pub fn max(self, other: Self) -> Self {
+                if other < self { self } else { other }
+            }
+
+            /// Restrict `self` to the interval `[lo, hi]`.
+            ///
+            /// Debug builds panic if `lo > hi`.
+            pub fn clamp(self, lo: Self, hi: Self) -> Self {
+                debug_assert!(lo <= hi, "clamp: lo must not exceed hi");
+                if self < lo {
+                    lo
+                } else if self > hi {
+                    hi
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,108 @@
+use crate::u256::Uint256;
+
+/// Montgomery arithmetic modulo a fixed odd 256-bit modulus.
+///
+/// Values in Montgomery form are `a * R mod m` with `R = 2^256`. Products
+/// are reduced with CIOS (coarsely integrated operand scanning) over the
+/// four limbs, which avoids the 512-by-256 division `Uint256::mulmod` does
+/// for every product. Converting in and out costs one multiplication each,
+/// so this pays off for exponentiation and other long chains of products.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Montgomery256 {
+    modulus: Uint256,
+    /// `-m^{-1} mod 2^64`
+    m_inv: u64,
+    /// `R^2 mod m`
+    r2: Uint256,
+}
+
+impl Montgomery256 {
+    /// Context for `modulus`, or `None` if it is even (including zero), since
+    /// Montgomery reduction needs `m` coprime to `R`.
+    pub fn new(modulus: Uint256) -> Option<Self> {
+        if modulus.is_even() {
+            return None;
+        }
+
+        // Newton's iteration for the inverse of the low limb: `3m ^ 2` is
+        // correct to 5 bits and each step doubles that, 5 -> 80 bits.
+        let m0 = modulus.l0;
+        let mut inv = m0.wrapping_mul(3) ^ 2;
+        for _ in 0..4 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+        }
+
+        // R mod m is (2^256 - m) mod m, which fits without a 257th bit.
+        let r1 = modulus.wrapping_neg() % modulus;
+        let r2 = r1.mulmod(r1, modulus);
+
+        Some(Self {
+            modulus,
+            m_inv: inv.wrapping_neg(),
+            r2,
+        })
+    }
+
+    pub fn modulus(&self) -> Uint256 {
+        self.modulus
+    }
+
+    /// Convert `a` into Montgomery form, reducing it mod `m` first.
+    pub fn to_mont(&self, a: Uint256) -> Uint256 {
+        self.mul(a % self.modulus, self.r2)
+    }
+
+    /// Convert out of Montgomery form.
+    pub fn from_mont(&self, a: Uint256) -> Uint256 {
+        self.mul(a, Uint256::ONE)
+    }
+
+    /// Montgomery product `a * b * R^{-1} mod m` of two values below `m`.
+    ///
+    /// Each outer step adds `a * b[i]`, then a multiple of `m` chosen to
+    /// clear the low limb, and shifts down one limb. The running value stays
+    /// below `2m`, so one conditional subtraction finishes the reduction.
+    pub fn mul(&self, a: Uint256, b: Uint256) -> Uint256 {
+        let a = [a.l0, a.l1, a.l2, a.l3];
+        let b = [b.l0, b.l1, b.l2, b.l3];
+        let m = self.modulus;
+        let n = [m.l0, m.l1, m.l2, m.l3];
+
+        let mut t = [0u64; 6];
+        for &bi in &b {
+            let mut carry = 0u64;
+            for j in 0..4 {
+                let s = t[j] as u128 + a[j] as u128 * bi as u128 + carry as u128;
+                t[j] = s as u64;
+                carry = (s >> 64) as u64;
+            }
+            let s = t[4] as u128 + carry as u128;
+            t[4] = s as u64;
+            t[5] = (s >> 64) as u64;
+
+            let q = t[0].wrapping_mul(self.m_inv);
+            let s = t[0] as u128 + q as u128 * n[0] as u128;
+            let mut carry = (s >> 64) as u64;
+            for j in 1..4 {
+                let s = t[j] as u128 + q as u128 * n[j] as u128 + carry as u128;
+                t[j - 1] = s as u64;
+                carry = (s >> 64) as u64;
+            }
+            let s = t[4] as u128 + carry as u128;
+            t[3] = s as u64;
+            t[4] = t[5] + (s >> 64) as u64;
+        }
+
+        let r = Uint256 {
+            l0: t[0],
+            l1: t[1],
+            l2: t[2],
+            l3: t[3],
+        };
+        if t[4] != 0 || r >= m {
+            r.wrapping_sub(m)
+        } else {
+            r
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! `num-traits` integration (enabled by the `num-traits` feature).
+//!
+//! The arithmetic operators already wrap, so the `Wrapping*` impls forward
+//! to them. The 64- and 128-bit types parse through the native integer of
+//! the same width; the 256-bit types use the crate's own parser.
+
+use crate::error::{IntErrorKind, ParseIntError};
+use crate::{Int64, Int128, Int256, Uint64, Uint128, Uint256};
+use num_traits::{Bounded, CheckedAdd, CheckedMul, Num, One, WrappingAdd, WrappingMul, Zero};
+
+macro_rules! impl_num_traits {
+    ($t:ty) => {
+        impl Zero for $t {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Bounded for $t {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl CheckedAdd for $t {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *v)
+            }
+        }
+
+        impl CheckedMul for $t {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *v)
+            }
+        }
+
+        impl WrappingAdd for $t {
+            fn wrapping_add(&self, v: &Self) -> Self {
+                *self + *v
+            }
+        }
+
+        impl WrappingMul for $t {
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                *self * *v
+            }
+        }
+    };
+}
+
+impl_num_traits!(Uint64);
+impl_num_traits!(Int64);
+impl_num_traits!(Uint128);
+impl_num_traits!(Int128);
+impl_num_traits!(Uint256);
+impl_num_traits!(Int256);
+
+/// Translate a native parse error into the crate's own error type.
+fn from_core(e: core::num::ParseIntError) -> ParseIntError {
+    use core::num::IntErrorKind as Core;
+    ParseIntError::new(match e.kind() {
+        Core::Empty => IntErrorKind::Empty,
+        Core::PosOverflow => IntErrorKind::PosOverflow,
+        Core::NegOverflow => IntErrorKind::NegOverflow,
+        _ => IntErrorKind::InvalidDigit,
+    })
+}
+
+impl Num for Uint64 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        let v = u64::from_str_radix(s, radix).map_err(from_core)?;
+        Ok(Self::new(v as u32, (v >> 32) as u32))
+    }
+}
+
+impl Num for Int64 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        let v = i64::from_str_radix(s, radix).map_err(from_core)? as u64;
+        Ok(Self::new(v as u32, (v >> 32) as u32))
+    }
+}
+
+impl Num for Uint128 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        u128::from_str_radix(s, radix)
+            .map(Self::from)
+            .map_err(from_core)
+    }
+}
+
+impl Num for Int128 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        i128::from_str_radix(s, radix)
+            .map(Self::from)
+            .map_err(from_core)
+    }
+}
+
+impl Num for Uint256 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        Uint256::from_str_radix(s, radix)
+    }
+}
+
+impl Num for Int256 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        Int256::from_str_radix(s, radix)
+    }
+}
@@ -0,0 +1,81 @@
+//! Random values via the `rand` crate (enabled by the `rand` feature).
+//!
+//! `Standard` fills every limb independently, so all bit patterns are
+//! equally likely, including negative values for the signed types.
+
+use crate::{Int64, Int128, Int256, Uint64, Uint128, Uint256};
+use rand::Rng;
+use rand::distributions::{Distribution, Standard};
+
+impl Distribution<Uint64> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Uint64 {
+        Uint64::new(rng.next_u32(), rng.next_u32())
+    }
+}
+
+impl Distribution<Int64> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int64 {
+        Int64::new(rng.next_u32(), rng.next_u32())
+    }
+}
+
+impl Distribution<Uint128> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Uint128 {
+        Uint128 {
+            l: rng.next_u64(),
+            h: rng.next_u64(),
+        }
+    }
+}
+
+impl Distribution<Int128> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int128 {
+        Int128::new(rng.next_u64(), rng.next_u64())
+    }
+}
+
+impl Distribution<Uint256> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Uint256 {
+        Uint256 {
+            l0: rng.next_u64(),
+            l1: rng.next_u64(),
+            l2: rng.next_u64(),
+            l3: rng.next_u64(),
+        }
+    }
+}
+
+impl Distribution<Int256> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int256 {
+        Int256::new(
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+        )
+    }
+}
+
+impl Uint256 {
+    /// Uniform value in `[0, bound)`.
+    ///
+    /// Rejection sampling: draws are masked to the bit length of `bound`,
+    /// so each one is accepted with probability at least 1/2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn random_below<R: Rng + ?Sized>(rng: &mut R, bound: Uint256) -> Uint256 {
+        if bound.is_zero() {
+            panic!("cannot sample empty range");
+        }
+        let mask = Uint256::MAX >> bound.leading_zeros();
+        loop {
+            let v: Uint256 = Standard.sample(rng);
+            let v = v & mask;
+            if v < bound {
+                return v;
+            }
+        }
+    }
+}
@@ -2,9 +2,15 @@
 //!
 //! Tests verify our implementations match native integer behavior.
 
+// 256-bit properties take eight limb arguments so quickcheck can drive every limb.
+#![allow(clippy::too_many_arguments)]
+
 use quickcheck_macros::quickcheck;
 
-use crate::{Int64, Int128, Int256, Uint64, Uint128, Uint256};
+use crate::{
+    Barrett256, BigUint, Int64, Int128, Int256, Int512, IntErrorKind, Montgomery256,
+    TryFromIntError, TryFromSliceError, Uint64, Uint128, Uint256, Uint512,
+};
 
 // ============================================================================
 // Int64 property tests - compare against native i64
@@ -15,6 +21,15 @@ fn int64_roundtrip(v: i64) -> bool {
     Int64::from_i64(v).to_i64() == v
 }
 
+#[quickcheck]
+fn int64_bytes(v: i64) -> bool {
+    let a = Int64::from_i64(v);
+    a.to_le_bytes() == v.to_le_bytes()
+        && a.to_be_bytes() == v.to_be_bytes()
+        && Int64::from_le_bytes(v.to_le_bytes()) == a
+        && Int64::from_be_bytes(v.to_be_bytes()) == a
+}
+
 #[quickcheck]
 fn int64_add(a: i64, b: i64) -> bool {
     let expected = a.wrapping_add(b);
@@ -131,6 +146,69 @@ fn int64_signum(a: i64) -> bool {
     result == expected
 }
 
+#[quickcheck]
+fn int64_checked_add(a: i64, b: i64) -> bool {
+    let expected = a.checked_add(b);
+    let result = Int64::from_i64(a).checked_add(Int64::from_i64(b)).map(Int64::to_i64);
+    result == expected
+}
+
+#[quickcheck]
+fn int64_checked_sub(a: i64, b: i64) -> bool {
+    let expected = a.checked_sub(b);
+    let result = Int64::from_i64(a).checked_sub(Int64::from_i64(b)).map(Int64::to_i64);
+    result == expected
+}
+
+#[quickcheck]
+fn int64_checked_mul(a: i64, b: i64) -> bool {
+    let expected = a.checked_mul(b);
+    let result = Int64::from_i64(a).checked_mul(Int64::from_i64(b)).map(Int64::to_i64);
+    result == expected
+}
+
+#[quickcheck]
+fn int64_overflowing_add(a: i64, b: i64) -> bool {
+    let expected = a.overflowing_add(b);
+    let (result, overflow) = Int64::from_i64(a).overflowing_add(Int64::from_i64(b));
+    (result.to_i64(), overflow) == expected
+}
+
+#[quickcheck]
+fn int64_overflowing_sub(a: i64, b: i64) -> bool {
+    let expected = a.overflowing_sub(b);
+    let (result, overflow) = Int64::from_i64(a).overflowing_sub(Int64::from_i64(b));
+    (result.to_i64(), overflow) == expected
+}
+
+#[quickcheck]
+fn int64_overflowing_mul(a: i64, b: i64) -> bool {
+    let expected = a.overflowing_mul(b);
+    let (result, overflow) = Int64::from_i64(a).overflowing_mul(Int64::from_i64(b));
+    (result.to_i64(), overflow) == expected
+}
+
+#[quickcheck]
+fn int64_saturating_add(a: i64, b: i64) -> bool {
+    let expected = a.saturating_add(b);
+    let result = Int64::from_i64(a).saturating_add(Int64::from_i64(b)).to_i64();
+    result == expected
+}
+
+#[quickcheck]
+fn int64_saturating_sub(a: i64, b: i64) -> bool {
+    let expected = a.saturating_sub(b);
+    let result = Int64::from_i64(a).saturating_sub(Int64::from_i64(b)).to_i64();
+    result == expected
+}
+
+#[quickcheck]
+fn int64_saturating_mul(a: i64, b: i64) -> bool {
+    let expected = a.saturating_mul(b);
+    let result = Int64::from_i64(a).saturating_mul(Int64::from_i64(b)).to_i64();
+    result == expected
+}
+
 // ============================================================================
 // Int128 property tests - compare against native i128
 // ============================================================================
@@ -140,6 +218,15 @@ fn int128_roundtrip(v: i128) -> bool {
     Int128::from_i128(v).to_i128() == v
 }
 
+#[quickcheck]
+fn int128_bytes(v: i128) -> bool {
+    let a = Int128::from_i128(v);
+    a.to_le_bytes() == v.to_le_bytes()
+        && a.to_be_bytes() == v.to_be_bytes()
+        && Int128::from_le_bytes(v.to_le_bytes()) == a
+        && Int128::from_be_bytes(v.to_be_bytes()) == a
+}
+
 #[quickcheck]
 fn int128_add(a: i128, b: i128) -> bool {
     let expected = a.wrapping_add(b);
@@ -256,6 +343,69 @@ fn int128_signum(a: i128) -> bool {
     result == expected
 }
 
+#[quickcheck]
+fn int128_checked_add(a: i128, b: i128) -> bool {
+    let expected = a.checked_add(b);
+    let result = Int128::from_i128(a).checked_add(Int128::from_i128(b)).map(Int128::to_i128);
+    result == expected
+}
+
+#[quickcheck]
+fn int128_checked_sub(a: i128, b: i128) -> bool {
+    let expected = a.checked_sub(b);
+    let result = Int128::from_i128(a).checked_sub(Int128::from_i128(b)).map(Int128::to_i128);
+    result == expected
+}
+
+#[quickcheck]
+fn int128_checked_mul(a: i128, b: i128) -> bool {
+    let expected = a.checked_mul(b);
+    let result = Int128::from_i128(a).checked_mul(Int128::from_i128(b)).map(Int128::to_i128);
+    result == expected
+}
+
+#[quickcheck]
+fn int128_overflowing_add(a: i128, b: i128) -> bool {
+    let expected = a.overflowing_add(b);
+    let (result, overflow) = Int128::from_i128(a).overflowing_add(Int128::from_i128(b));
+    (result.to_i128(), overflow) == expected
+}
+
+#[quickcheck]
+fn int128_overflowing_sub(a: i128, b: i128) -> bool {
+    let expected = a.overflowing_sub(b);
+    let (result, overflow) = Int128::from_i128(a).overflowing_sub(Int128::from_i128(b));
+    (result.to_i128(), overflow) == expected
+}
+
+#[quickcheck]
+fn int128_overflowing_mul(a: i128, b: i128) -> bool {
+    let expected = a.overflowing_mul(b);
+    let (result, overflow) = Int128::from_i128(a).overflowing_mul(Int128::from_i128(b));
+    (result.to_i128(), overflow) == expected
+}
+
+#[quickcheck]
+fn int128_saturating_add(a: i128, b: i128) -> bool {
+    let expected = a.saturating_add(b);
+    let result = Int128::from_i128(a).saturating_add(Int128::from_i128(b)).to_i128();
+    result == expected
+}
+
+#[quickcheck]
+fn int128_saturating_sub(a: i128, b: i128) -> bool {
+    let expected = a.saturating_sub(b);
+    let result = Int128::from_i128(a).saturating_sub(Int128::from_i128(b)).to_i128();
+    result == expected
+}
+
+#[quickcheck]
+fn int128_saturating_mul(a: i128, b: i128) -> bool {
+    let expected = a.saturating_mul(b);
+    let result = Int128::from_i128(a).saturating_mul(Int128::from_i128(b)).to_i128();
+    result == expected
+}
+
 // ============================================================================
 // Int256 property tests
 //
@@ -391,9 +541,8 @@ fn int256_neg_neg(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
 }
 
 // Division identity: a == (a / b) * b + (a % b) for non-zero b
-// Only test with positive values to avoid Uint256 multiplication edge case with MAX limbs
 #[quickcheck]
-fn int256_div_rem_identity(a: u64, b: u64) -> bool {
+fn int256_div_rem_identity(a: i64, b: i64) -> bool {
     if b == 0 {
         return true;
     }
@@ -441,9 +590,38 @@ fn int256_sign_pos_neg(a: u32, b: u32) -> bool {
     (x * y).is_negative()
 }
 
-// Note: negative * negative sign test is omitted because small negative values
-// like -1 become all-MAX in unsigned representation, which triggers a known
-// edge case in Uint256 multiplication where multiple column sums overflow u128.
+// Sign test: negative * negative = positive (for small values)
+// Small negatives are all-MAX in the upper limbs, so every column of the
+// unsigned product overflows u128 at least once.
+#[quickcheck]
+fn int256_sign_neg_neg(a: u32, b: u32) -> bool {
+    if a == 0 || b == 0 {
+        return true;
+    }
+    let x = -Int256::new(a as u64, 0, 0, 0);
+    let y = -Int256::new(b as u64, 0, 0, 0);
+    let p = x * y;
+    p.is_positive() && p == Int256::from_i128(a as i128 * b as i128)
+}
+
+// All-MAX limbs drive the column carries to their largest values.
+#[test]
+fn uint256_mul_all_max_limbs() {
+    let max = Uint256::MAX;
+    let expect = |a: Uint256, b: Uint256| from_ethnum(to_ethnum(&a).wrapping_mul(to_ethnum(&b)));
+    let low3 = Uint256 { l0: u64::MAX, l1: u64::MAX, l2: u64::MAX, l3: 0 };
+    let low2 = Uint256::from_u128(u128::MAX);
+    let cases = [(max, max), (max, low3), (low2, max), (low3, low3), (max, Uint256::from_u128(2))];
+    for (a, b) in cases {
+        assert_eq!(a * b, expect(a, b));
+        assert_eq!(a.wrapping_mul(b), expect(a, b));
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(crate::optimal_u256_mul(&a, &b), expect(a, b));
+    }
+    assert_eq!(max * max, Uint256::ONE);
+    assert_eq!(Int256::NEG_ONE * Int256::NEG_ONE, Int256::ONE);
+    assert_eq!(Int256::MIN * Int256::NEG_ONE, Int256::MIN);
+}
 
 // Shift: (a << n) >> n preserves value for small shifts
 #[quickcheck]
@@ -485,6 +663,250 @@ fn int256_bitnot_bitnot(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
     !!a == a
 }
 
+#[quickcheck]
+fn int256_display_i128(v: i128) -> bool {
+    Int256::from_i128(v).to_string() == v.to_string()
+}
+
+#[test]
+fn int256_display_edges() {
+    assert_eq!(Int256::ZERO.to_string(), "0");
+    assert_eq!(Int256::NEG_ONE.to_string(), "-1");
+    assert_eq!(
+        Int256::MIN.to_string(),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(
+        Int256::MAX.to_string(),
+        "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+    );
+    assert_eq!(format!("{:+}", Int256::ONE), "+1");
+    assert_eq!(format!("{:>4}", Int256::NEG_ONE), "  -1");
+    assert_eq!(format!("{:04}", Int256::NEG_ONE), "-001");
+}
+
+#[quickcheck]
+fn int256_radix_fmt_i128(v: i128) -> bool {
+    // Sign-extended values print as their full 256-bit pattern, so compare
+    // against i128 only for non-negative values
+    let a = Int256::from_i128(v);
+    if v < 0 {
+        return format!("{a:x}") == format!("{:x}", to_ethnum_i256(&a));
+    }
+    format!("{a:x}") == format!("{v:x}")
+        && format!("{a:#X}") == format!("{v:#X}")
+        && format!("{a:b}") == format!("{v:b}")
+        && format!("{a:o}") == format!("{v:o}")
+}
+
+#[test]
+fn int256_radix_fmt_edges() {
+    assert_eq!(format!("{:x}", Int256::NEG_ONE), "f".repeat(64));
+    assert_eq!(format!("{:#x}", Int256::MIN), format!("0x8{}", "0".repeat(63)));
+    assert_eq!(format!("{:b}", Int256::NEG_ONE), "1".repeat(256));
+}
+
+#[quickcheck]
+fn int256_from_str_radix_i128(v: i128, which: u8) -> bool {
+    let m = v.unsigned_abs();
+    let (radix, digits) = match which % 4 {
+        0 => (2, format!("{m:b}")),
+        1 => (8, format!("{m:o}")),
+        2 => (10, format!("{m}")),
+        _ => (16, format!("{m:x}")),
+    };
+    let s = if v < 0 { format!("-{digits}") } else { digits };
+    i128::from_str_radix(&s, radix) == Ok(v)
+        && Int256::from_str_radix(&s, radix) == Ok(Int256::from_i128(v))
+}
+
+#[quickcheck]
+fn int256_hex_matches_i128(v: i128) -> bool {
+    // Sign extension puts 32 extra hex digits of the fill in front.
+    let (lower, upper) = if v < 0 { ("f", "F") } else { ("", "") };
+    let x = Int256::from_i128(v);
+    let bits = format!("{x:x}");
+    bits == format!("{}{v:x}", lower.repeat(32))
+        && format!("{x:X}") == format!("{}{v:X}", upper.repeat(32))
+        && Int256::from_str_radix(&bits, 16).ok() == (!x.is_negative()).then_some(x)
+}
+
+#[test]
+fn int256_from_str_radix_edges() {
+    assert_eq!(Int256::from_str_radix("-ff", 16), Ok(Int256::from_i128(-255)));
+    assert_eq!(Int256::from_str_radix("-0x8", 16), Ok(Int256::from_i128(-8)));
+    assert_eq!(Int256::from_str_radix("-1z", 36), Ok(Int256::from_i128(-71)));
+    assert_eq!(
+        Int256::from_str_radix(&format!("-8{}", "0".repeat(63)), 16),
+        Ok(Int256::MIN)
+    );
+    assert_eq!(
+        Int256::from_str_radix(&format!("7{}", "f".repeat(63)), 16),
+        Ok(Int256::MAX)
+    );
+    // The raw bits of -1 do not parse back as -1, as with i128.
+    let neg_one_bits = "f".repeat(64);
+    assert_eq!(format!("{:x}", Int256::NEG_ONE), neg_one_bits);
+    assert_eq!(
+        *Int256::from_str_radix(&neg_one_bits, 16).unwrap_err().kind(),
+        IntErrorKind::PosOverflow
+    );
+}
+
+#[test]
+#[should_panic(expected = "from_str_radix_int: must lie in the range `[2, 36]` - found 37")]
+fn int256_from_str_radix_bad_radix() {
+    let _ = Int256::from_str_radix("1", 37);
+}
+
+#[quickcheck]
+fn int256_parse_display_roundtrip(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    a.to_string().parse::<Int256>() == Ok(a)
+}
+
+#[quickcheck]
+fn int256_parse_i128(v: i128) -> bool {
+    v.to_string().parse::<Int256>() == Ok(Int256::from_i128(v))
+}
+
+#[test]
+fn int256_parse_edges() {
+    let kind = |s: &str| *s.parse::<Int256>().unwrap_err().kind();
+    let min = "57896044618658097711785492504343953926634992332820282019728792003956564819968";
+    let max = "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+    let max_plus_one = min;
+    let min_minus_one = "57896044618658097711785492504343953926634992332820282019728792003956564819969";
+
+    assert_eq!("-0".parse::<Int256>(), Ok(Int256::ZERO));
+    assert_eq!("+0".parse::<Int256>(), Ok(Int256::ZERO));
+    assert_eq!("-1".parse::<Int256>(), Ok(Int256::NEG_ONE));
+    assert_eq!(format!("-{min}").parse::<Int256>(), Ok(Int256::MIN));
+    assert_eq!(max.parse::<Int256>(), Ok(Int256::MAX));
+    assert_eq!(format!("+{max}").parse::<Int256>(), Ok(Int256::MAX));
+
+    assert_eq!(kind(max_plus_one), IntErrorKind::PosOverflow);
+    assert_eq!(kind(&format!("-{min_minus_one}")), IntErrorKind::NegOverflow);
+    assert_eq!(kind(&format!("-1{}", "0".repeat(100))), IntErrorKind::NegOverflow);
+    assert_eq!(kind(&format!("1{}", "0".repeat(100))), IntErrorKind::PosOverflow);
+
+    assert_eq!(kind(""), IntErrorKind::Empty);
+    assert_eq!(kind("-"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("+"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("--1"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("+-1"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("1-"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("0x10"), IntErrorKind::InvalidDigit);
+}
+
+fn to_ethnum_i256(i: &Int256) -> ethnum::I256 {
+    let bytes = [
+        i.l0.to_le_bytes(),
+        i.l1.to_le_bytes(),
+        i.l2.to_le_bytes(),
+        i.l3.to_le_bytes(),
+    ].concat();
+    ethnum::I256::from_le_bytes(bytes.try_into().unwrap())
+}
+
+#[quickcheck]
+fn int256_checked_add(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    let b = Int256::new(m0, m1, m2, m3);
+    let expected = to_ethnum_i256(&a).checked_add(to_ethnum_i256(&b));
+    a.checked_add(b).map(|r| to_ethnum_i256(&r)) == expected
+}
+
+#[quickcheck]
+fn int256_checked_sub(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    let b = Int256::new(m0, m1, m2, m3);
+    let expected = to_ethnum_i256(&a).checked_sub(to_ethnum_i256(&b));
+    a.checked_sub(b).map(|r| to_ethnum_i256(&r)) == expected
+}
+
+// Operands are sign-extended from i128 so products land on both sides of the 256-bit range
+#[quickcheck]
+fn int256_checked_mul(a: i128, b: i128, shift: u8) -> bool {
+    let x = Int256::from_i128(a) << (shift % 128) as u32;
+    let y = Int256::from_i128(b);
+    let expected = to_ethnum_i256(&x).checked_mul(to_ethnum_i256(&y));
+    x.checked_mul(y).map(|r| to_ethnum_i256(&r)) == expected
+}
+
+#[test]
+fn int256_checked_boundaries() {
+    assert_eq!(Int256::MAX.checked_add(Int256::ONE), None);
+    assert_eq!(Int256::MIN.checked_sub(Int256::ONE), None);
+    assert_eq!(Int256::MIN.checked_add(Int256::NEG_ONE), None);
+    assert_eq!(Int256::MAX.checked_sub(Int256::NEG_ONE), None);
+    assert_eq!(Int256::MAX.checked_add(Int256::ZERO), Some(Int256::MAX));
+    assert_eq!(Int256::MIN.checked_add(Int256::MAX), Some(Int256::NEG_ONE));
+    assert_eq!(Int256::ZERO.checked_sub(Int256::MAX), Some(Int256::MIN + Int256::ONE));
+    assert_eq!(Int256::ZERO.checked_sub(Int256::MIN), None);
+
+    assert_eq!(Int256::MIN.checked_mul(Int256::ONE), Some(Int256::MIN));
+    assert_eq!(Int256::MIN.checked_mul(Int256::NEG_ONE), None);
+    assert_eq!(Int256::MAX.checked_mul(Int256::NEG_ONE), Some(Int256::MIN + Int256::ONE));
+    assert_eq!(Int256::NEG_ONE.checked_mul(Int256::NEG_ONE), Some(Int256::ONE));
+    // -2^254 * 2 == MIN exactly, 2^254 * 2 overflows
+    let half = Int256::ONE << 254u32;
+    let two = Int256::from_i128(2);
+    assert_eq!((-half).checked_mul(two), Some(Int256::MIN));
+    assert_eq!(half.checked_mul(two), None);
+}
+
+#[quickcheck]
+fn int256_overflowing_add(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    let b = Int256::new(m0, m1, m2, m3);
+    let expected = to_ethnum_i256(&a).overflowing_add(to_ethnum_i256(&b));
+    let (result, overflow) = a.overflowing_add(b);
+    (to_ethnum_i256(&result), overflow) == expected
+}
+
+#[quickcheck]
+fn int256_overflowing_sub(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    let b = Int256::new(m0, m1, m2, m3);
+    let expected = to_ethnum_i256(&a).overflowing_sub(to_ethnum_i256(&b));
+    let (result, overflow) = a.overflowing_sub(b);
+    (to_ethnum_i256(&result), overflow) == expected
+}
+
+#[quickcheck]
+fn int256_overflowing_mul(a: i128, b: i128, shift: u8) -> bool {
+    let x = Int256::from_i128(a) << (shift % 128) as u32;
+    let y = Int256::from_i128(b);
+    let expected = to_ethnum_i256(&x).overflowing_mul(to_ethnum_i256(&y));
+    let (result, overflow) = x.overflowing_mul(y);
+    (to_ethnum_i256(&result), overflow) == expected
+}
+
+#[quickcheck]
+fn int256_saturating_add(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    let b = Int256::new(m0, m1, m2, m3);
+    let expected = to_ethnum_i256(&a).saturating_add(to_ethnum_i256(&b));
+    to_ethnum_i256(&a.saturating_add(b)) == expected
+}
+
+#[quickcheck]
+fn int256_saturating_sub(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    let b = Int256::new(m0, m1, m2, m3);
+    let expected = to_ethnum_i256(&a).saturating_sub(to_ethnum_i256(&b));
+    to_ethnum_i256(&a.saturating_sub(b)) == expected
+}
+
+#[quickcheck]
+fn int256_saturating_mul(a: i128, b: i128, shift: u8) -> bool {
+    let x = Int256::from_i128(a) << (shift % 128) as u32;
+    let y = Int256::from_i128(b);
+    let expected = to_ethnum_i256(&x).saturating_mul(to_ethnum_i256(&y));
+    to_ethnum_i256(&x.saturating_mul(y)) == expected
+}
+
 // ============================================================================
 // Uint64 property tests - compare against native u64
 // ============================================================================
@@ -494,6 +916,15 @@ fn uint64_roundtrip(v: u64) -> bool {
     Uint64::from_u64(v).to_u64() == v
 }
 
+#[quickcheck]
+fn uint64_bytes(v: u64) -> bool {
+    let a = Uint64::from_u64(v);
+    a.to_le_bytes() == v.to_le_bytes()
+        && a.to_be_bytes() == v.to_be_bytes()
+        && Uint64::from_le_bytes(v.to_le_bytes()) == a
+        && Uint64::from_be_bytes(v.to_be_bytes()) == a
+}
+
 #[quickcheck]
 fn uint64_add(a: u64, b: u64) -> bool {
     let expected = a.wrapping_add(b);
@@ -550,10 +981,141 @@ fn uint64_widening_mul(a: u64, b: u64) -> bool {
     result == expected
 }
 
+#[quickcheck]
+fn uint64_checked_add(a: u64, b: u64) -> bool {
+    let expected = a.checked_add(b);
+    let result = Uint64::from_u64(a).checked_add(Uint64::from_u64(b)).map(Uint64::to_u64);
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_checked_sub(a: u64, b: u64) -> bool {
+    let expected = a.checked_sub(b);
+    let result = Uint64::from_u64(a).checked_sub(Uint64::from_u64(b)).map(Uint64::to_u64);
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_checked_mul(a: u64, b: u64) -> bool {
+    let expected = a.checked_mul(b);
+    let result = Uint64::from_u64(a).checked_mul(Uint64::from_u64(b)).map(Uint64::to_u64);
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_overflowing_add(a: u64, b: u64) -> bool {
+    let expected = a.overflowing_add(b);
+    let (result, overflow) = Uint64::from_u64(a).overflowing_add(Uint64::from_u64(b));
+    (result.to_u64(), overflow) == expected
+}
+
+#[quickcheck]
+fn uint64_overflowing_sub(a: u64, b: u64) -> bool {
+    let expected = a.overflowing_sub(b);
+    let (result, overflow) = Uint64::from_u64(a).overflowing_sub(Uint64::from_u64(b));
+    (result.to_u64(), overflow) == expected
+}
+
+#[quickcheck]
+fn uint64_overflowing_mul(a: u64, b: u64) -> bool {
+    let expected = a.overflowing_mul(b);
+    let (result, overflow) = Uint64::from_u64(a).overflowing_mul(Uint64::from_u64(b));
+    (result.to_u64(), overflow) == expected
+}
+
+#[quickcheck]
+fn uint64_saturating_add(a: u64, b: u64) -> bool {
+    let expected = a.saturating_add(b);
+    let result = Uint64::from_u64(a).saturating_add(Uint64::from_u64(b)).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_saturating_sub(a: u64, b: u64) -> bool {
+    let expected = a.saturating_sub(b);
+    let result = Uint64::from_u64(a).saturating_sub(Uint64::from_u64(b)).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_saturating_mul(a: u64, b: u64) -> bool {
+    let expected = a.saturating_mul(b);
+    let result = Uint64::from_u64(a).saturating_mul(Uint64::from_u64(b)).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_shl(a: u64, shift: u8) -> bool {
+    let shift = (shift % 64) as u32;
+    let expected = a << shift;
+    let result = (Uint64::from_u64(a) << shift).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_shr(a: u64, shift: u8) -> bool {
+    let shift = (shift % 64) as u32;
+    let expected = a >> shift; // logical shift for u64
+    let result = (Uint64::from_u64(a) >> shift).to_u64();
+    result == expected
+}
+
+#[test]
+fn uint64_shift_edges() {
+    let a = Uint64::from_u64(0xDEAD_BEEF_CAFE_F00D);
+    assert_eq!((a << 0).to_u64(), 0xDEAD_BEEF_CAFE_F00D);
+    assert_eq!((a >> 0).to_u64(), 0xDEAD_BEEF_CAFE_F00D);
+    assert_eq!((a << 32).to_u64(), 0xCAFE_F00D_0000_0000);
+    assert_eq!((a >> 32).to_u64(), 0xDEAD_BEEF);
+    assert_eq!((a << 63).to_u64(), 1 << 63);
+    assert_eq!((a >> 63).to_u64(), 1);
+    assert_eq!(a << 64, Uint64::ZERO);
+    assert_eq!(a >> 64, Uint64::ZERO);
+}
+
+#[quickcheck]
+fn uint64_bitand(a: u64, b: u64) -> bool {
+    let expected = a & b;
+    let result = (Uint64::from_u64(a) & Uint64::from_u64(b)).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_bitor(a: u64, b: u64) -> bool {
+    let expected = a | b;
+    let result = (Uint64::from_u64(a) | Uint64::from_u64(b)).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_bitxor(a: u64, b: u64) -> bool {
+    let expected = a ^ b;
+    let result = (Uint64::from_u64(a) ^ Uint64::from_u64(b)).to_u64();
+    result == expected
+}
+
+#[quickcheck]
+fn uint64_bitnot(a: u64) -> bool {
+    let expected = !a;
+    let result = (!Uint64::from_u64(a)).to_u64();
+    result == expected
+}
+
 // ============================================================================
 // Uint128 property tests - compare against native u128
 // ============================================================================
 
+fn to_native_u128(u: Uint128) -> u128 {
+    ((u.h as u128) << 64) | (u.l as u128)
+}
+
+fn from_native_u128(v: u128) -> Uint128 {
+    Uint128 {
+        l: v as u64,
+        h: (v >> 64) as u64,
+    }
+}
+
 #[quickcheck]
 fn uint128_roundtrip(h: u64, l: u64) -> bool {
     let v = ((h as u128) << 64) | (l as u128);
@@ -562,6 +1124,15 @@ fn uint128_roundtrip(h: u64, l: u64) -> bool {
     result == v
 }
 
+#[quickcheck]
+fn uint128_bytes(v: u128) -> bool {
+    let a = from_native_u128(v);
+    a.to_le_bytes() == v.to_le_bytes()
+        && a.to_be_bytes() == v.to_be_bytes()
+        && Uint128::from_le_bytes(v.to_le_bytes()) == a
+        && Uint128::from_be_bytes(v.to_be_bytes()) == a
+}
+
 #[quickcheck]
 fn uint128_add(a_h: u64, a_l: u64, b_h: u64, b_l: u64) -> bool {
     let a = ((a_h as u128) << 64) | (a_l as u128);
@@ -644,23 +1215,146 @@ fn uint128_cmp(a_h: u64, a_l: u64, b_h: u64, b_l: u64) -> bool {
     ua.cmp(&ub) == expected
 }
 
-// ============================================================================
-// Uint256 property tests - compare against ethnum::U256
-// ============================================================================
+#[quickcheck]
+fn uint128_checked_add(a: u128, b: u128) -> bool {
+    let expected = a.checked_add(b);
+    let result = from_native_u128(a).checked_add(from_native_u128(b)).map(to_native_u128);
+    result == expected
+}
 
-fn to_ethnum(u: &Uint256) -> ethnum::U256 {
-    let bytes = [
-        u.l0.to_le_bytes(),
-        u.l1.to_le_bytes(),
-        u.l2.to_le_bytes(),
-        u.l3.to_le_bytes(),
-    ].concat();
-    ethnum::U256::from_le_bytes(bytes.try_into().unwrap())
+#[quickcheck]
+fn uint128_checked_sub(a: u128, b: u128) -> bool {
+    let expected = a.checked_sub(b);
+    let result = from_native_u128(a).checked_sub(from_native_u128(b)).map(to_native_u128);
+    result == expected
 }
 
-fn from_ethnum(e: ethnum::U256) -> Uint256 {
-    let bytes = e.to_le_bytes();
-    Uint256 {
+#[quickcheck]
+fn uint128_checked_mul(a: u128, b: u128, shift: u8) -> bool {
+    // Shift b down so that non-overflowing products are also exercised
+    let b = b >> (shift % 128);
+    let expected = a.checked_mul(b);
+    let result = from_native_u128(a).checked_mul(from_native_u128(b)).map(to_native_u128);
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_overflowing_add(a: u128, b: u128) -> bool {
+    let expected = a.overflowing_add(b);
+    let (result, overflow) = from_native_u128(a).overflowing_add(from_native_u128(b));
+    (to_native_u128(result), overflow) == expected
+}
+
+#[quickcheck]
+fn uint128_overflowing_sub(a: u128, b: u128) -> bool {
+    let expected = a.overflowing_sub(b);
+    let (result, overflow) = from_native_u128(a).overflowing_sub(from_native_u128(b));
+    (to_native_u128(result), overflow) == expected
+}
+
+#[quickcheck]
+fn uint128_overflowing_mul(a: u128, b: u128) -> bool {
+    let expected = a.overflowing_mul(b);
+    let (result, overflow) = from_native_u128(a).overflowing_mul(from_native_u128(b));
+    (to_native_u128(result), overflow) == expected
+}
+
+#[quickcheck]
+fn uint128_saturating_add(a: u128, b: u128) -> bool {
+    let expected = a.saturating_add(b);
+    let result = to_native_u128(from_native_u128(a).saturating_add(from_native_u128(b)));
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_saturating_sub(a: u128, b: u128) -> bool {
+    let expected = a.saturating_sub(b);
+    let result = to_native_u128(from_native_u128(a).saturating_sub(from_native_u128(b)));
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_saturating_mul(a: u128, b: u128) -> bool {
+    let expected = a.saturating_mul(b);
+    let result = to_native_u128(from_native_u128(a).saturating_mul(from_native_u128(b)));
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_shl(a: u128, shift: u8) -> bool {
+    let shift = (shift % 128) as u32;
+    let expected = a << shift;
+    let result = to_native_u128(from_native_u128(a) << shift);
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_shr(a: u128, shift: u8) -> bool {
+    let shift = (shift % 128) as u32;
+    let expected = a >> shift;
+    let result = to_native_u128(from_native_u128(a) >> shift);
+    result == expected
+}
+
+#[test]
+fn uint128_shift_edges() {
+    let v = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+    let a = from_native_u128(v);
+    assert_eq!(to_native_u128(a << 0), v);
+    assert_eq!(to_native_u128(a >> 0), v);
+    assert_eq!(to_native_u128(a << 64), v << 64);
+    assert_eq!(to_native_u128(a >> 64), v >> 64);
+    assert_eq!(to_native_u128(a << 127), v << 127);
+    assert_eq!(to_native_u128(a >> 127), v >> 127);
+    assert_eq!(to_native_u128(a << 128), 0);
+    assert_eq!(to_native_u128(a >> 128), 0);
+}
+
+#[quickcheck]
+fn uint128_bitand(a: u128, b: u128) -> bool {
+    let expected = a & b;
+    let result = to_native_u128(from_native_u128(a) & from_native_u128(b));
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_bitor(a: u128, b: u128) -> bool {
+    let expected = a | b;
+    let result = to_native_u128(from_native_u128(a) | from_native_u128(b));
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_bitxor(a: u128, b: u128) -> bool {
+    let expected = a ^ b;
+    let result = to_native_u128(from_native_u128(a) ^ from_native_u128(b));
+    result == expected
+}
+
+#[quickcheck]
+fn uint128_bitnot(a: u128) -> bool {
+    let expected = !a;
+    let result = to_native_u128(!from_native_u128(a));
+    result == expected
+}
+
+// ============================================================================
+// Uint256 property tests - compare against ethnum::U256
+// ============================================================================
+
+fn to_ethnum(u: &Uint256) -> ethnum::U256 {
+    let bytes = [
+        u.l0.to_le_bytes(),
+        u.l1.to_le_bytes(),
+        u.l2.to_le_bytes(),
+        u.l3.to_le_bytes(),
+    ].concat();
+    ethnum::U256::from_le_bytes(bytes.try_into().unwrap())
+}
+
+fn from_ethnum(e: ethnum::U256) -> Uint256 {
+    let bytes = e.to_le_bytes();
+    Uint256 {
         l0: u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
         l1: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
         l2: u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
@@ -729,3 +1423,3063 @@ fn uint256_cmp(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3
 
     a.cmp(&b) == ea.cmp(&eb)
 }
+
+#[quickcheck]
+fn uint256_bitand(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let expected = from_ethnum(to_ethnum(&a) & to_ethnum(&b));
+
+    (a & b) == expected
+}
+
+#[quickcheck]
+fn uint256_bitor(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let expected = from_ethnum(to_ethnum(&a) | to_ethnum(&b));
+
+    (a | b) == expected
+}
+
+#[quickcheck]
+fn uint256_bitxor(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let expected = from_ethnum(to_ethnum(&a) ^ to_ethnum(&b));
+
+    (a ^ b) == expected
+}
+
+#[quickcheck]
+fn uint256_bitnot(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+
+    let expected = from_ethnum(!to_ethnum(&a));
+
+    !a == expected
+}
+
+#[quickcheck]
+fn uint256_checked_add(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let expected = to_ethnum(&a).checked_add(to_ethnum(&b)).map(from_ethnum);
+
+    a.checked_add(b) == expected
+}
+
+#[quickcheck]
+fn uint256_checked_sub(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let expected = to_ethnum(&a).checked_sub(to_ethnum(&b)).map(from_ethnum);
+
+    a.checked_sub(b) == expected
+}
+
+// Zeroing the top limbs of b keeps a good share of products below 2^256
+#[quickcheck]
+fn uint256_checked_mul(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, keep_high: bool) -> bool {
+    let a = Uint256 { l0, l1, l2, l3: if keep_high { l3 } else { 0 } };
+    let b = Uint256 { l0: m0, l1: m1, l2: 0, l3: 0 };
+
+    let expected = to_ethnum(&a).checked_mul(to_ethnum(&b)).map(from_ethnum);
+
+    a.checked_mul(b) == expected
+}
+
+#[test]
+fn uint256_checked_boundaries() {
+    let max = Uint256::MAX;
+    let one = Uint256::ONE;
+    let two = Uint256 { l0: 2, l1: 0, l2: 0, l3: 0 };
+
+    assert_eq!(max.checked_add(one), None);
+    assert_eq!(max.checked_add(Uint256::ZERO), Some(max));
+    assert_eq!(Uint256::ZERO.checked_sub(one), None);
+    assert_eq!(max.checked_sub(max), Some(Uint256::ZERO));
+    assert_eq!(max.checked_mul(one), Some(max));
+    assert_eq!(max.checked_mul(two), None);
+    assert_eq!(max.checked_mul(Uint256::ZERO), Some(Uint256::ZERO));
+
+    // 2^128 * 2^127 fits, 2^128 * 2^128 does not
+    let p128 = Uint256 { l0: 0, l1: 0, l2: 1, l3: 0 };
+    let p127 = Uint256 { l0: 0, l1: 1 << 63, l2: 0, l3: 0 };
+    assert_eq!(p128.checked_mul(p127), Some(Uint256 { l0: 0, l1: 0, l2: 0, l3: 1 << 63 }));
+    assert_eq!(p128.checked_mul(p128), None);
+}
+
+#[quickcheck]
+fn uint256_overflowing_add(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let (expected, expected_overflow) = to_ethnum(&a).overflowing_add(to_ethnum(&b));
+
+    a.overflowing_add(b) == (from_ethnum(expected), expected_overflow)
+}
+
+#[quickcheck]
+fn uint256_overflowing_sub(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    let (expected, expected_overflow) = to_ethnum(&a).overflowing_sub(to_ethnum(&b));
+
+    a.overflowing_sub(b) == (from_ethnum(expected), expected_overflow)
+}
+
+#[quickcheck]
+fn uint256_overflowing_sub_borrow(l0: u64, l1: u64, l2: u64, l3: u64, delta: i8, limb: u8) -> bool {
+    // b is a nudged by a small amount in one limb, so equal and adjacent
+    // values come up as often as distant ones.
+    let a = Uint256 { l0, l1, l2, l3 };
+    let step = Uint256::from(delta.unsigned_abs() as u64) << (64 * (limb as u32 % 4));
+    let b = if delta < 0 { a.wrapping_sub(step) } else { a.wrapping_add(step) };
+    let (diff, borrow) = a.overflowing_sub(b);
+    borrow == (a < b) && diff == a.wrapping_sub(b) && a.checked_sub(b).is_none() == borrow
+}
+
+#[quickcheck]
+fn uint256_overflowing_mul(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, keep_high: bool) -> bool {
+    let a = Uint256 { l0, l1, l2, l3: if keep_high { l3 } else { 0 } };
+    let b = Uint256 { l0: m0, l1: m1, l2: 0, l3: 0 };
+
+    let (expected, expected_overflow) = to_ethnum(&a).overflowing_mul(to_ethnum(&b));
+
+    a.overflowing_mul(b) == (from_ethnum(expected), expected_overflow)
+}
+
+#[test]
+fn uint256_saturating() {
+    let one = Uint256::ONE;
+    let two = Uint256 { l0: 2, l1: 0, l2: 0, l3: 0 };
+
+    assert_eq!(Uint256::MAX.saturating_add(one), Uint256::MAX);
+    assert_eq!(Uint256::MAX.saturating_sub(one) + one, Uint256::MAX);
+    assert_eq!(Uint256::ZERO.saturating_sub(one), Uint256::ZERO);
+    assert_eq!(one.saturating_sub(two), Uint256::ZERO);
+    assert_eq!(Uint256::MAX.saturating_mul(two), Uint256::MAX);
+    assert_eq!(two.saturating_mul(two), Uint256 { l0: 4, l1: 0, l2: 0, l3: 0 });
+}
+
+#[test]
+fn unsigned_constants() {
+    const U64: [Uint64; 3] = [Uint64::MIN, Uint64::ONE, Uint64::MAX];
+    const U128: [Uint128; 3] = [Uint128::MIN, Uint128::ONE, Uint128::MAX];
+    const U256: [Uint256; 3] = [Uint256::MIN, Uint256::ONE, Uint256::MAX];
+
+    assert_eq!(U64.map(Uint64::to_u64), [u64::MIN, 1, u64::MAX]);
+    assert_eq!(U128.map(to_native_u128), [u128::MIN, 1, u128::MAX]);
+    assert_eq!(U256.map(|u| to_ethnum(&u)), [ethnum::U256::MIN, ethnum::U256::ONE, ethnum::U256::MAX]);
+    assert_eq!(Uint256::MIN, Uint256::ZERO);
+    assert_eq!(Uint256::MAX + Uint256::ONE, Uint256::ZERO);
+}
+
+#[quickcheck]
+fn uint256_display(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    a.to_string() == to_ethnum(&a).to_string()
+}
+
+#[quickcheck]
+fn uint256_display_u128(v: u128) -> bool {
+    let a = Uint256 { l0: v as u64, l1: (v >> 64) as u64, l2: 0, l3: 0 };
+    a.to_string() == v.to_string()
+}
+
+#[test]
+fn uint256_display_edges() {
+    assert_eq!(Uint256::ZERO.to_string(), "0");
+    assert_eq!(Uint256::ONE.to_string(), "1");
+    assert_eq!(Uint256::MAX.to_string(), ethnum::U256::MAX.to_string());
+    assert_eq!(
+        Uint256::MAX.to_string(),
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    );
+    // 10^19 exactly exercises a zero-padded low chunk
+    let ten_pow_19 = Uint256 { l0: 10_000_000_000_000_000_000, l1: 0, l2: 0, l3: 0 };
+    assert_eq!(ten_pow_19.to_string(), "10000000000000000000");
+    assert_eq!(format!("{:>5}", Uint256::ONE), "    1");
+    assert_eq!(format!("{:05}", Uint256::ONE), "00001");
+}
+
+#[quickcheck]
+fn uint256_radix_fmt(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let e = to_ethnum(&a);
+    format!("{a:x}") == format!("{e:x}")
+        && format!("{a:X}") == format!("{e:X}")
+        && format!("{a:b}") == format!("{e:b}")
+        && format!("{a:o}") == format!("{e:o}")
+        && format!("{a:#066x}") == format!("{e:#066x}")
+}
+
+#[test]
+fn uint256_radix_fmt_edges() {
+    assert_eq!(format!("{:x}", Uint256::ZERO), "0");
+    assert_eq!(format!("{:#x}", Uint256::ZERO), "0x0");
+    assert_eq!(format!("{:#b}", Uint256::ONE), "0b1");
+    assert_eq!(format!("{:#o}", Uint256::ONE), "0o1");
+    assert_eq!(format!("{:064x}", Uint256::ONE), format!("{}1", "0".repeat(63)));
+    assert_eq!(format!("{:#010x}", Uint256::ONE), "0x00000001");
+    assert_eq!(format!("{:>6x}", Uint256::ONE), "     1");
+    assert_eq!(format!("{:X}", Uint256::MAX), "F".repeat(64));
+    assert_eq!(format!("{:o}", Uint256::MAX), format!("1{}", "7".repeat(85)));
+
+    // 1 << 200 sits in l3 with zero limbs below it that must be padded
+    let p200 = Uint256 { l0: 0, l1: 0, l2: 0, l3: 1 << 8 };
+    assert_eq!(format!("{p200:x}"), format!("1{}", "0".repeat(50)));
+    assert_eq!(format!("{p200:b}"), format!("1{}", "0".repeat(200)));
+    assert_eq!(format!("{p200:o}"), format!("4{}", "0".repeat(66)));
+
+    // zeros inside a limb boundary stay in place
+    let mixed = Uint256 { l0: 0xABC, l1: 1, l2: 0, l3: 0 };
+    assert_eq!(format!("{mixed:x}"), "10000000000000abc");
+}
+
+#[quickcheck]
+fn uint256_parse_display_roundtrip(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    a.to_string().parse::<Uint256>() == Ok(a)
+}
+
+#[quickcheck]
+fn uint256_parse_radix_roundtrip(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    Uint256::from_str_radix(&format!("{a:x}"), 16) == Ok(a)
+        && Uint256::from_str_radix(&format!("{a:#X}"), 16) == Ok(a)
+        && Uint256::from_str_radix(&format!("{a:#b}"), 2) == Ok(a)
+        && Uint256::from_str_radix(&format!("{a:#o}"), 8) == Ok(a)
+}
+
+#[quickcheck]
+fn uint256_parse_u128(v: u128, radix: u8) -> bool {
+    let radix = 2 + (radix % 35) as u32;
+    let mut digits = Vec::new();
+    let mut n = v;
+    loop {
+        digits.push(std::char::from_digit((n % radix as u128) as u32, radix).unwrap());
+        n /= radix as u128;
+        if n == 0 {
+            break;
+        }
+    }
+    let s: String = digits.iter().rev().collect();
+    let expected = Uint256 { l0: v as u64, l1: (v >> 64) as u64, l2: 0, l3: 0 };
+    Uint256::from_str_radix(&s, radix) == Ok(expected)
+}
+
+#[test]
+fn uint256_parse_edges() {
+    let kind = |s: &str| *s.parse::<Uint256>().unwrap_err().kind();
+
+    assert_eq!("0".parse::<Uint256>(), Ok(Uint256::ZERO));
+    assert_eq!("+1".parse::<Uint256>(), Ok(Uint256::ONE));
+    assert_eq!("0000000001".parse::<Uint256>(), Ok(Uint256::ONE));
+    assert_eq!(Uint256::MAX.to_string().parse::<Uint256>(), Ok(Uint256::MAX));
+    assert_eq!(Uint256::from_str_radix("0x", 16).unwrap_err().kind(), &IntErrorKind::InvalidDigit);
+    // prefixes only apply to their own radix: "0b1" is a hex number
+    assert_eq!(Uint256::from_str_radix("0b1", 16), Ok(Uint256 { l0: 0xb1, l1: 0, l2: 0, l3: 0 }));
+
+    assert_eq!(kind(""), IntErrorKind::Empty);
+    assert_eq!(kind("+"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("-1"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("12a"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind(" 1"), IntErrorKind::InvalidDigit);
+    // MAX + 1
+    assert_eq!(
+        kind("115792089237316195423570985008687907853269984665640564039457584007913129639936"),
+        IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        Uint256::from_str_radix(&format!("1{}", "0".repeat(64)), 16).unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+}
+
+#[test]
+fn default_is_zero() {
+    assert_eq!(Uint256::default(), Uint256::ZERO);
+    assert_eq!(Int256::default(), Int256::ZERO);
+    assert_eq!(Uint128::default(), Uint128::ZERO);
+    assert_eq!(Int128::default(), Int128::ZERO);
+    assert_eq!(Uint64::default(), Uint64::ZERO);
+    assert_eq!(Int64::default(), Int64::ZERO);
+}
+
+#[quickcheck]
+fn uint256_pow(base: u32, exp: u8) -> bool {
+    let exp = (exp % 64) as u32;
+    let a = Uint256 { l0: base as u64, l1: 0, l2: 0, l3: 0 };
+    let expected = to_ethnum(&a).checked_pow(exp).map(from_ethnum);
+    let native_ok = match (base as u128).checked_pow(exp) {
+        Some(n) => a.pow(exp) == from_ethnum(ethnum::U256::from(n)),
+        None => true,
+    };
+    native_ok && a.checked_pow(exp) == expected && a.pow(exp) == from_ethnum(to_ethnum(&a).wrapping_pow(exp))
+}
+
+#[test]
+fn uint256_pow_edges() {
+    let two = Uint256 { l0: 2, l1: 0, l2: 0, l3: 0 };
+    let top_bit = Uint256 { l0: 0, l1: 0, l2: 0, l3: 1 << 63 };
+    assert_eq!(two.pow(255), top_bit);
+    assert_eq!(two.checked_pow(255), Some(top_bit));
+    assert_eq!(two.checked_pow(256), None);
+    assert_eq!(two.pow(256), Uint256::ZERO);
+    assert_eq!(Uint256::ZERO.pow(0), Uint256::ONE);
+    assert_eq!(Uint256::MAX.checked_pow(1), Some(Uint256::MAX));
+    assert_eq!(Uint256 { l0: 10, l1: 0, l2: 0, l3: 0 }.pow(77).to_string(), format!("1{}", "0".repeat(77)));
+}
+
+#[quickcheck]
+fn int256_pow(base: i32, exp: u8) -> bool {
+    let exp = (exp % 64) as u32;
+    let a = Int256::from_i128(base as i128);
+    let expected = to_ethnum_i256(&a).checked_pow(exp);
+    let native_ok = match (base as i128).checked_pow(exp) {
+        Some(n) => a.pow(exp) == Int256::from_i128(n),
+        None => true,
+    };
+    native_ok
+        && a.checked_pow(exp).map(|r| to_ethnum_i256(&r)) == expected
+        && to_ethnum_i256(&a.pow(exp)) == to_ethnum_i256(&a).wrapping_pow(exp)
+}
+
+#[test]
+fn int256_pow_edges() {
+    let neg_two = Int256::from_i128(-2);
+    assert_eq!(neg_two.checked_pow(255), Some(Int256::MIN));
+    assert_eq!(neg_two.checked_pow(254), Some(Int256::new(0, 0, 0, 1 << 62)));
+    assert_eq!(Int256::from_i128(2).checked_pow(255), None);
+    assert_eq!(neg_two.checked_pow(256), None);
+    assert_eq!(Int256::NEG_ONE.checked_pow(u32::MAX), Some(Int256::NEG_ONE));
+    assert_eq!(Int256::MIN.checked_pow(1), Some(Int256::MIN));
+    assert_eq!(Int256::MIN.checked_pow(2), None);
+}
+
+#[quickcheck]
+fn uint256_shifts(l0: u64, l1: u64, l2: u64, l3: u64, n: u16) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let n = (n % 300) as u32;
+    let ea = to_ethnum(&a);
+    let (shl, shr) = if n < 256 {
+        (ea << n, ea >> n)
+    } else {
+        (ethnum::U256::ZERO, ethnum::U256::ZERO)
+    };
+    a << n == from_ethnum(shl) && a >> n == from_ethnum(shr)
+}
+
+#[quickcheck]
+fn uint256_isqrt(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let r = a.isqrt();
+    let next = r + Uint256::ONE;
+    let upper_ok = match next.checked_mul(next) {
+        Some(sq) => a < sq,
+        None => true,
+    };
+    r * r <= a && upper_ok
+}
+
+#[quickcheck]
+fn uint256_isqrt_u128(v: u128) -> bool {
+    let a = from_ethnum(ethnum::U256::from(v));
+    a.isqrt() == from_ethnum(ethnum::U256::from(v.isqrt()))
+}
+
+#[test]
+fn uint256_isqrt_edges() {
+    let r = Uint256::MAX.isqrt();
+    assert_eq!(r, Uint256 { l0: u64::MAX, l1: u64::MAX, l2: 0, l3: 0 });
+    assert!(r.checked_mul(r).is_some());
+    assert_eq!(Uint256::ZERO.isqrt(), Uint256::ZERO);
+    assert_eq!(Uint256::ONE.isqrt(), Uint256::ONE);
+    let three = Uint256 { l0: 3, l1: 0, l2: 0, l3: 0 };
+    assert_eq!(three.isqrt(), Uint256::ONE);
+}
+
+#[quickcheck]
+fn bit_counts_small(a: u64, b: i64, c: u128, d: i128) -> bool {
+    let ua = Uint64::from_u64(a);
+    let ib = Int64::from_i64(b);
+    let uc = from_native_u128(c);
+    let id = Int128::from_i128(d);
+    ua.count_ones() == a.count_ones()
+        && ua.count_zeros() == a.count_zeros()
+        && ua.trailing_zeros() == a.trailing_zeros()
+        && ua.trailing_ones() == a.trailing_ones()
+        && ib.count_ones() == b.count_ones()
+        && ib.count_zeros() == b.count_zeros()
+        && ib.trailing_zeros() == b.trailing_zeros()
+        && ib.trailing_ones() == b.trailing_ones()
+        && uc.count_ones() == c.count_ones()
+        && uc.count_zeros() == c.count_zeros()
+        && uc.trailing_zeros() == c.trailing_zeros()
+        && uc.trailing_ones() == c.trailing_ones()
+        && id.count_ones() == d.count_ones()
+        && id.count_zeros() == d.count_zeros()
+        && id.trailing_zeros() == d.trailing_zeros()
+        && id.trailing_ones() == d.trailing_ones()
+}
+
+#[quickcheck]
+fn bit_counts_256(l0: u64, l1: u64, l2: u64, l3: u64, low_zeros: u8, low_ones: u8) -> bool {
+    // Clear or set a run of low bits so the multi-limb paths get exercised.
+    let u = Uint256 { l0, l1, l2, l3 } >> (low_zeros as u32) << (low_zeros as u32);
+    let u = u | !(Uint256::MAX << (low_ones as u32));
+    let i = Int256::from_uint256(u);
+    let e = to_ethnum(&u);
+    let ei = to_ethnum_i256(&i);
+    u.count_ones() == e.count_ones()
+        && u.count_zeros() == e.count_zeros()
+        && u.trailing_zeros() == e.trailing_zeros()
+        && u.trailing_ones() == e.trailing_ones()
+        && i.count_ones() == ei.count_ones()
+        && i.count_zeros() == ei.count_zeros()
+        && i.trailing_zeros() == ei.trailing_zeros()
+        && i.trailing_ones() == ei.trailing_ones()
+}
+
+#[test]
+fn bit_counts_edges() {
+    assert_eq!(Uint64::ZERO.trailing_zeros(), 64);
+    assert_eq!(Int64::NEG_ONE.trailing_ones(), 64);
+    assert_eq!(Uint128::ZERO.trailing_zeros(), 128);
+    assert_eq!(Int128::NEG_ONE.trailing_ones(), 128);
+    assert_eq!(Uint256::ZERO.trailing_zeros(), 256);
+    assert_eq!(Uint256::MAX.trailing_ones(), 256);
+    assert_eq!(Int256::ZERO.trailing_zeros(), 256);
+    assert_eq!(Int256::NEG_ONE.trailing_ones(), 256);
+    assert_eq!(Int256::MIN.trailing_zeros(), 255);
+    assert_eq!(Int256::MIN.count_ones(), 1);
+}
+
+#[quickcheck]
+fn uint256_div_rem(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    // Vary the divisor width so every dispatch path is exercised.
+    let b = match m3 % 4 {
+        0 => Uint256 { l0: m0, l1: 0, l2: 0, l3: 0 },
+        1 => Uint256 { l0: m0, l1: m1, l2: 0, l3: 0 },
+        2 => Uint256 { l0: m0, l1: m1, l2: m2, l3: 0 },
+        _ => Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 },
+    };
+    if b.is_zero() {
+        return true;
+    }
+
+    let (q, r) = a.div_rem(b);
+    let (ea, eb) = (to_ethnum(&a), to_ethnum(&b));
+    q == from_ethnum(ea / eb) && r == from_ethnum(ea % eb) && q * b + r == a && r < b
+}
+
+#[quickcheck]
+fn uint256_div_u128_top_bit_set(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64) -> bool {
+    // Divisors in [2^127, 2^128) need no normalization shift.
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1 | 1 << 63, l2: 0, l3: 0 };
+    let (ea, eb) = (to_ethnum(&a), to_ethnum(&b));
+    a.div_rem(b) == (from_ethnum(ea / eb), from_ethnum(ea % eb))
+}
+
+#[test]
+fn uint256_div_u128_shift_zero_regression() {
+    let a = Uint256 { l0: 0x0123_4567_89AB_CDEF, l1: u64::MAX, l2: 0xDEAD_BEEF, l3: u64::MAX };
+    let b = Uint256::from_u128(0xFFFF_FFFF_FFFF_FFFF_0000_0000_0000_0000);
+    let (ea, eb) = (to_ethnum(&a), to_ethnum(&b));
+    assert_eq!(a / b, from_ethnum(ea / eb));
+    assert_eq!(a % b, from_ethnum(ea % eb));
+    assert_eq!(Uint256::MAX / Uint256::from_u128(1 << 127), Uint256::MAX >> 127);
+}
+
+#[quickcheck]
+fn uint256_rem(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = match m3 % 4 {
+        0 => Uint256 { l0: m0, l1: 0, l2: 0, l3: 0 },
+        1 => Uint256 { l0: m0, l1: m1, l2: 0, l3: 0 },
+        2 => Uint256 { l0: m0, l1: m1, l2: m2, l3: 0 },
+        _ => Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 },
+    };
+    if b.is_zero() {
+        return true;
+    }
+
+    a % b == from_ethnum(to_ethnum(&a) % to_ethnum(&b))
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn uint256_rem_by_zero() {
+    let _ = Uint256::ONE % Uint256::ZERO;
+}
+
+/// Restoring long division one bit at a time, as a reference for the Knuth
+/// paths that is simple enough to check by eye.
+fn div_rem_slow(a: Uint256, b: Uint256) -> (Uint256, Uint256) {
+    let mut q = Uint256::ZERO;
+    let mut r = Uint256::ZERO;
+    for i in (0..256).rev() {
+        // If the shift carries out of bit 255 the true 257-bit remainder is
+        // above b, and the wrapping subtraction still gives the right value.
+        let carry = r.bit(255);
+        r = r << 1u32 | Uint256::from(a.bit(i) as u64);
+        if carry || r >= b {
+            r = r.wrapping_sub(b);
+            q |= Uint256::ONE << i;
+        }
+    }
+    (q, r)
+}
+
+#[quickcheck]
+fn uint256_div_rem_matches_slow(
+    a: (u64, u64, u64, u64),
+    d: (u64, u64, u64, u64),
+    width: u8,
+    shift: u8,
+) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    // Cover every divisor width, then shift down so the top limb is not
+    // always full and the normalization shift varies.
+    let d = Uint256 { l0: d.0, l1: d.1, l2: d.2, l3: d.3 };
+    let b = (d >> (64 * (3 - width as u32 % 4))) >> (shift as u32 % 64);
+    if b.is_zero() {
+        return true;
+    }
+    let slow = div_rem_slow(a, b);
+    a.div_rem(b) == slow && (a / b, a % b) == slow
+}
+
+#[test]
+fn uint256_div_rem_matches_slow_limb_patterns() {
+    // Every combination of limbs at the edges of qhat estimation: empty,
+    // one, the top bit alone, and all ones.
+    let limbs = [0, 1, 1 << 63, u64::MAX];
+    let values: Vec<Uint256> = (0..limbs.len().pow(4))
+        .map(|mut i| {
+            let mut l = [0; 4];
+            for limb in &mut l {
+                *limb = limbs[i % limbs.len()];
+                i /= limbs.len();
+            }
+            Uint256::from_limbs(l)
+        })
+        .collect();
+    for &a in &values {
+        for &b in values.iter().filter(|b| !b.is_zero()) {
+            assert_eq!(a.div_rem(b), div_rem_slow(a, b), "{a:?} / {b:?}");
+        }
+    }
+}
+
+#[quickcheck]
+fn uint256_div_knuth_small_top_limb(q: u128, l0: u64, l1: u64, top: u8, under: u64, four: bool) -> bool {
+    // A small top limb leaves most of the divisor below the two limbs the
+    // quotient estimate sees, so the refinement loop and the add-back step
+    // both get exercised. Dividends just below a multiple of the divisor
+    // make the estimate overshoot most often.
+    let top = top as u64 | 1;
+    let (d, q) = if four {
+        (Uint256 { l0, l1, l2: l0 ^ l1, l3: top }, Uint256::from(q as u64 >> 8))
+    } else {
+        (Uint256 { l0, l1, l2: top, l3: 0 }, Uint256::from(q >> 8))
+    };
+    let multiple = q * d;
+    let a = multiple - Uint256::from(under).min(multiple);
+    let (ea, ed) = (to_ethnum(&a), to_ethnum(&d));
+    a.div_rem(d) == (from_ethnum(ea / ed), from_ethnum(ea % ed))
+}
+
+#[test]
+fn uint256_div_knuth_add_back() {
+    // v = 2^191 + 2^64 - 1 and a = k * 2^191. The estimate from the top
+    // two divisor limbs is k, but k * v exceeds a by k * (2^64 - 1), so the
+    // true quotient is k - 1 and the 3-limb add-back path must run.
+    let d = Uint256 { l0: u64::MAX, l1: 0, l2: 1 << 63, l3: 0 };
+    for k in [2u64, 3, 0xFFFF, u64::MAX] {
+        let a = Uint256::from(k) << 191u32;
+        let (q, r) = a.div_rem(d);
+        assert_eq!(q, Uint256::from(k - 1));
+        assert_eq!(r, a - q * d);
+        let (ea, ed) = (to_ethnum(&a), to_ethnum(&d));
+        assert_eq!((to_ethnum(&q), to_ethnum(&r)), (ea / ed, ea % ed));
+    }
+}
+
+#[quickcheck]
+fn int256_div_rem_full(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Int256::new(l0, l1, l2, l3);
+    // Sign-extend a narrower divisor half the time so small quotients and
+    // the u64/u128 unsigned paths show up alongside full-width ones.
+    let b = if m3 & 1 == 0 {
+        Int256::from_i128(((m1 as i128) << 64 | m0 as i128) >> (m2 % 128))
+    } else {
+        Int256::new(m0, m1, m2, m3)
+    };
+    if b.is_zero() || (a == Int256::MIN && b == Int256::NEG_ONE) {
+        return true;
+    }
+
+    let (ea, eb) = (to_ethnum_i256(&a), to_ethnum_i256(&b));
+    to_ethnum_i256(&(a / b)) == ea / eb && to_ethnum_i256(&(a % b)) == ea % eb
+}
+
+#[test]
+fn int256_div_rem_min() {
+    let cases = [
+        (Int256::MIN, Int256::ONE),
+        (Int256::MIN, Int256::from_i128(-2)),
+        (Int256::MIN, Int256::from_i128(3)),
+        (Int256::MIN, Int256::MIN),
+        (Int256::MIN, Int256::MAX),
+        (Int256::MAX, Int256::MIN),
+        (Int256::NEG_ONE, Int256::MIN),
+        (Int256::from_i128(-7), Int256::from_i128(2)),
+        (Int256::from_i128(7), Int256::from_i128(-2)),
+    ];
+    for (a, b) in cases {
+        let (ea, eb) = (to_ethnum_i256(&a), to_ethnum_i256(&b));
+        assert_eq!(to_ethnum_i256(&(a / b)), ea / eb);
+        assert_eq!(to_ethnum_i256(&(a % b)), ea % eb);
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn int256_div_min_by_neg_one() {
+    let _ = Int256::MIN / Int256::NEG_ONE;
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with overflow")]
+fn int256_rem_min_by_neg_one() {
+    let _ = Int256::MIN % Int256::NEG_ONE;
+}
+
+#[quickcheck]
+fn uint256_widening_mul(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+
+    // Reference: split into 128-bit halves so each partial product fits in
+    // a U256, then recombine with explicit carries.
+    let (ea, eb) = (to_ethnum(&a), to_ethnum(&b));
+    let mask = ethnum::U256::from(u128::MAX);
+    let (a0, a1): (ethnum::U256, ethnum::U256) = (ea & mask, ea >> 128);
+    let (b0, b1): (ethnum::U256, ethnum::U256) = (eb & mask, eb >> 128);
+    let (mid, mid_carry) = (a0 * b1).overflowing_add(a1 * b0);
+    let (lo, lo_carry) = (a0 * b0).overflowing_add(mid << 128);
+    let hi = a1 * b1
+        + (mid >> 128)
+        + if mid_carry { ethnum::U256::ONE << 128 } else { ethnum::U256::ZERO }
+        + if lo_carry { ethnum::U256::ONE } else { ethnum::U256::ZERO };
+
+    a.widening_mul(b) == (from_ethnum(hi), from_ethnum(lo))
+}
+
+#[quickcheck]
+fn uint256_widening_mul_u128(a: u128, b: u128) -> bool {
+    let (x, y) = (from_ethnum(ethnum::U256::from(a)), from_ethnum(ethnum::U256::from(b)));
+    let expected = ethnum::U256::from(a) * ethnum::U256::from(b);
+    x.widening_mul(y) == (Uint256::ZERO, from_ethnum(expected))
+}
+
+#[test]
+fn uint256_widening_mul_max() {
+    // (2^256 - 1)^2 = (2^256 - 2) * 2^256 + 1
+    let (hi, lo) = Uint256::MAX.widening_mul(Uint256::MAX);
+    assert_eq!(hi, Uint256::MAX - Uint256::ONE);
+    assert_eq!(lo, Uint256::ONE);
+}
+
+#[quickcheck]
+fn uint256_overflowing_mul_full_above_2_128(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    // Force a set bit at or above 2^128 in both operands, so the product
+    // is at least 2^256.
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2 | 1, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 | 1 };
+    let (lo, overflow) = a.overflowing_mul_full(b);
+    overflow && lo == a * b && (lo, overflow) == a.overflowing_mul(b)
+}
+
+#[quickcheck]
+fn uint256_overflowing_mul_full_u128(a: u128, b: u128) -> bool {
+    let (x, y) = (Uint256::from(a), Uint256::from(b));
+    x.overflowing_mul_full(y) == (from_ethnum(ethnum::U256::from(a) * ethnum::U256::from(b)), false)
+}
+
+#[quickcheck]
+fn uint128_widening_mul(a: u128, b: u128) -> bool {
+    let (hi, lo) = from_native_u128(a).widening_mul(from_native_u128(b));
+    let product = Uint256 { l0: lo.l, l1: lo.h, l2: hi.l, l3: hi.h };
+    product == from_ethnum(ethnum::U256::from(a) * ethnum::U256::from(b))
+}
+
+#[test]
+fn uint128_widening_mul_max() {
+    let (hi, lo) = Uint128::MAX.widening_mul(Uint128::MAX);
+    assert_eq!(to_native_u128(hi), u128::MAX - 1);
+    assert_eq!(to_native_u128(lo), 1);
+}
+
+/// Reference `(a * b) mod m` by double-and-add over the bits of `a`, keeping
+/// every intermediate below `m` so nothing exceeds 256 bits.
+fn ethnum_mulmod(a: ethnum::U256, b: ethnum::U256, m: ethnum::U256) -> ethnum::U256 {
+    let add = |x: ethnum::U256, y: ethnum::U256| if x >= m - y { x - (m - y) } else { x + y };
+    let b = b % m;
+    let mut r = ethnum::U256::ZERO;
+    for i in (0..256).rev() {
+        r = add(r, r);
+        if (a >> i) & ethnum::U256::ONE == ethnum::U256::ONE {
+            r = add(r, b);
+        }
+    }
+    r
+}
+
+#[quickcheck]
+fn uint256_mulmod(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m3, l1: m2, l2: m1, l3: m0 };
+    // Vary the modulus width so every divisor limb count is exercised.
+    let m = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 } >> (l0 as u32 % 256);
+    if m.is_zero() {
+        return true;
+    }
+
+    let r = a.mulmod(b, m);
+    r < m && to_ethnum(&r) == ethnum_mulmod(to_ethnum(&a), to_ethnum(&b), to_ethnum(&m))
+}
+
+#[test]
+fn uint256_mulmod_edges() {
+    let max = Uint256::MAX;
+    assert_eq!(max.mulmod(max, max), Uint256::ZERO);
+    // (2^256 - 1)^2 mod (2^256 - 2) = 1
+    assert_eq!(max.mulmod(max, max - Uint256::ONE), Uint256::ONE);
+    let top = Uint256 { l0: 0, l1: 0, l2: 0, l3: 1 << 63 };
+    // 2^256 = 1 mod (2^256 - 1), so 2^510 reduces to 2^254
+    assert_eq!(top.mulmod(top, max), top >> 1);
+    assert_eq!(Uint256::ONE.mulmod(Uint256::ONE, Uint256::ONE), Uint256::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn uint256_mulmod_by_zero() {
+    let _ = Uint256::ONE.mulmod(Uint256::ONE, Uint256::ZERO);
+}
+
+#[quickcheck]
+fn uint256_powmod(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, exp: u8) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let m = Uint256 { l0: m0, l1: m1, l2: m2, l3: 0 } >> (l3 as u32 % 192);
+    if m.is_zero() {
+        return true;
+    }
+    let e = Uint256 { l0: exp as u64, l1: 0, l2: 0, l3: 0 };
+
+    let (ea, em) = (to_ethnum(&a), to_ethnum(&m));
+    let mut expected = ethnum::U256::ONE % em;
+    for _ in 0..exp {
+        expected = ethnum_mulmod(expected, ea, em);
+    }
+
+    let r = a.powmod(e, m);
+    r < m && to_ethnum(&r) == expected
+}
+
+#[test]
+fn uint256_powmod_fermat() {
+    // p = 2^255 - 19 is prime, so a^(p-1) = 1 and a^p = a for 0 < a < p
+    let p = Uint256 { l0: u64::MAX - 18, l1: u64::MAX, l2: u64::MAX, l3: u64::MAX >> 1 };
+    let p_minus_1 = p - Uint256::ONE;
+    for a in [2u64, 3, 12345, u64::MAX] {
+        let a = Uint256 { l0: a, l1: 0, l2: 0, l3: 0 };
+        assert_eq!(a.powmod(p_minus_1, p), Uint256::ONE);
+        assert_eq!(a.powmod(p, p), a);
+    }
+    let big = p - Uint256 { l0: 2, l1: 0, l2: 0, l3: 0 };
+    assert_eq!(big.powmod(p_minus_1, p), Uint256::ONE);
+}
+
+#[test]
+fn uint256_powmod_edges() {
+    let seven = Uint256 { l0: 7, l1: 0, l2: 0, l3: 0 };
+    assert_eq!(seven.powmod(Uint256::ZERO, seven), Uint256::ONE);
+    assert_eq!(seven.powmod(Uint256::ZERO, Uint256::ONE), Uint256::ZERO);
+    assert_eq!(Uint256::ZERO.powmod(Uint256::ZERO, seven), Uint256::ONE);
+    assert_eq!(Uint256::MAX.powmod(Uint256::MAX, Uint256::MAX), Uint256::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn uint256_powmod_by_zero() {
+    let _ = Uint256::ONE.powmod(Uint256::ONE, Uint256::ZERO);
+}
+
+#[quickcheck]
+fn uint256_addmod_submod(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m3, l1: m2, l2: m1, l3: m0 };
+    let m = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 } >> (l0 as u32 % 256);
+    if m.is_zero() {
+        return true;
+    }
+
+    let (ea, eb, em) = (to_ethnum(&a) % to_ethnum(&m), to_ethnum(&b) % to_ethnum(&m), to_ethnum(&m));
+    let sum = if ea >= em - eb { ea - (em - eb) } else { ea + eb };
+    let diff = if ea >= eb { ea - eb } else { em - (eb - ea) };
+
+    let (s, d) = (a.addmod(b, m), a.submod(b, m));
+    s < m && d < m && to_ethnum(&s) == sum && to_ethnum(&d) == diff
+}
+
+#[test]
+fn uint256_addmod_submod_edges() {
+    let max = Uint256::MAX;
+    let m = max - Uint256::ONE;
+    // (2^256 - 1) + (2^256 - 1) = 2^257 - 2 = 2 * (2^256 - 2) + 2
+    assert_eq!(max.addmod(max, m), Uint256 { l0: 2, l1: 0, l2: 0, l3: 0 });
+    assert_eq!(max.addmod(max, max), Uint256::ZERO);
+    assert_eq!(Uint256::ZERO.submod(Uint256::ONE, max), m);
+    assert_eq!(Uint256::ONE.submod(max, m), Uint256::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn uint256_addmod_by_zero() {
+    let _ = Uint256::ONE.addmod(Uint256::ONE, Uint256::ZERO);
+}
+
+#[quickcheck]
+fn widening_from_unsigned(a: u64, b: u128) -> bool {
+    let a64 = Uint64::from_u64(a);
+    let b128 = from_native_u128(b);
+    to_native_u128(Uint128::from(a64)) == a as u128
+        && Uint256::from(a64) == from_ethnum(ethnum::U256::from(a))
+        && Uint256::from(b128) == from_ethnum(ethnum::U256::from(b))
+        && Uint256::from(Uint128::from(a64)) == Uint256::from(a64)
+}
+
+#[quickcheck]
+fn widening_from_signed(a: i64, b: i128) -> bool {
+    let a64 = Int64::from_i64(a);
+    let b128 = Int128::from_i128(b);
+    Int128::from(a64).to_i128() == a as i128
+        && Int256::from(a64).to_i128() == a as i128
+        && Int256::from(b128).to_i128() == b
+        && Int256::from(Int128::from(a64)) == Int256::from(a64)
+}
+
+#[test]
+fn widening_from_edges() {
+    assert_eq!(Int256::from(Int64::NEG_ONE), Int256::NEG_ONE);
+    assert_eq!(Int256::from(Int128::MIN).to_i128(), i128::MIN);
+    assert!(Int256::from(Int64::MIN).is_negative());
+    assert_eq!(Int128::from(Int64::MAX).to_i128(), i64::MAX as i128);
+    assert_eq!(Uint256::from(Uint128::MAX), Uint256 { l0: u64::MAX, l1: u64::MAX, l2: 0, l3: 0 });
+    assert_eq!(to_native_u128(Uint128::from(Uint64::MAX)), u64::MAX as u128);
+}
+
+#[quickcheck]
+fn narrowing_try_from_unsigned(l0: u64, l1: u64, l2: u64, l3: u64, zeros: u8) -> bool {
+    let v = Uint256 { l0, l1, l2, l3 } >> (zeros as u32 % 256);
+    let e = to_ethnum(&v);
+    let u128_ok = match Uint128::try_from(v) {
+        Ok(n) => Ok(to_native_u128(n)) == u128::try_from(e).map_err(|_| ()),
+        Err(_) => u128::try_from(e).is_err(),
+    };
+    let u64_ok = match Uint64::try_from(v) {
+        Ok(n) => Ok(n.to_u64()) == u64::try_from(e).map_err(|_| ()),
+        Err(_) => u64::try_from(e).is_err(),
+    };
+    u128_ok && u64_ok
+}
+
+#[quickcheck]
+fn narrowing_try_from_signed(a: i128, shift: u8) -> bool {
+    let a = a >> (shift % 128);
+    let v = Int256::from_i128(a);
+    Int128::try_from(v).map(Int128::to_i128) == Ok(a)
+        && Int64::try_from(v).map(Int64::to_i64).ok() == i64::try_from(a).ok()
+        && Int64::try_from(Int128::from_i128(a)).map(Int64::to_i64).ok() == i64::try_from(a).ok()
+        && Uint128::try_from(Int128::from_i128(a)).map(to_native_u128).ok() == u128::try_from(a).ok()
+}
+
+#[test]
+fn narrowing_try_from_edges() {
+    let u128_max = Uint256 { l0: u64::MAX, l1: u64::MAX, l2: 0, l3: 0 };
+    assert_eq!(Uint128::try_from(u128_max).map(to_native_u128), Ok(u128::MAX));
+    assert_eq!(Uint128::try_from(u128_max + Uint256::ONE), Err(TryFromIntError(())));
+    assert_eq!(Uint64::try_from(from_native_u128(u64::MAX as u128)).map(Uint64::to_u64), Ok(u64::MAX));
+    assert!(Uint64::try_from(from_native_u128(1 << 64)).is_err());
+
+    assert_eq!(Int128::try_from(Int256::from_i128(i128::MIN)).map(Int128::to_i128), Ok(i128::MIN));
+    assert!(Int128::try_from(Int256::from_i128(i128::MIN) - Int256::ONE).is_err());
+    assert!(Int128::try_from(Int256::from_i128(i128::MAX) + Int256::ONE).is_err());
+    assert!(Int64::try_from(Int256::from_i128(i64::MIN as i128 - 1)).is_err());
+
+    // Negative into unsigned, and unsigned past the signed maximum
+    assert!(Uint256::try_from(Int256::NEG_ONE).is_err());
+    assert_eq!(Uint256::try_from(Int256::MAX), Ok(Uint256::MAX >> 1));
+    assert_eq!(Int256::try_from(Uint256::MAX >> 1), Ok(Int256::MAX));
+    assert!(Int256::try_from(Uint256::MAX).is_err());
+    assert!(Uint128::try_from(Int128::MIN).is_err());
+    assert!(Int128::try_from(Uint128::MAX).is_err());
+    assert!(Uint64::try_from(Int64::from_i64(-1)).is_err());
+    assert!(Int64::try_from(Uint64::MAX).is_err());
+    assert_eq!(Int64::try_from(Uint64::from_u64(i64::MAX as u64)).map(Int64::to_i64), Ok(i64::MAX));
+
+    assert_eq!(
+        TryFromIntError(()).to_string(),
+        "out of range integral type conversion attempted"
+    );
+}
+
+#[quickcheck]
+fn from_primitives(a: u128, b: u64, c: i128, d: i64) -> bool {
+    Uint256::from(a) == from_ethnum(ethnum::U256::from(a))
+        && Uint256::from(b) == Uint256 { l0: b, l1: 0, l2: 0, l3: 0 }
+        && to_native_u128(Uint128::from(a)) == a
+        && to_native_u128(Uint128::from(b)) == b as u128
+        && Int256::from(c).to_i128() == c
+        && Int256::from(d).to_i128() == d as i128
+        && Int128::from(c).to_i128() == c
+        && Int128::from(d).to_i128() == d as i128
+}
+
+#[test]
+fn from_primitives_edges() {
+    assert_eq!(Uint256::from(5u64), Uint256 { l0: 5, l1: 0, l2: 0, l3: 0 });
+    assert_eq!(Uint256::from(5u32), Uint256::from(5u8));
+    assert_eq!(to_native_u128(Uint128::from(u32::MAX)), u32::MAX as u128);
+    assert_eq!(to_native_u128(Uint128::from(7u8)), 7);
+    assert_eq!(Int256::from(-1i64), Int256::NEG_ONE);
+    assert_eq!(Int256::from(i128::MIN), Int256::new(0, 1 << 63, u64::MAX, u64::MAX));
+    assert_eq!(Int128::from(-1i64), Int128::NEG_ONE);
+    assert_eq!(Int128::from(i64::MIN).to_i128(), i64::MIN as i128);
+}
+
+#[quickcheck]
+fn uint256_u128_roundtrip(v: u128, l2: u64, l3: u64) -> bool {
+    let u = Uint256::from_u128(v);
+    let wide = Uint256 { l2, l3, ..u };
+    u == from_ethnum(ethnum::U256::from(v)) && u.to_u128() == v && wide.to_u128() == v
+}
+
+#[test]
+fn uint256_assign_ops() {
+    let a = Uint256::from(1_000_000u64) * Uint256::from(u128::MAX);
+    let b = Uint256::from(12345u64);
+    let mut x = a;
+    x += b;
+    assert_eq!(x, a + b);
+    x -= a;
+    assert_eq!(x, b);
+    x *= a;
+    assert_eq!(x, a * b);
+    x /= b;
+    assert_eq!(x, a);
+    x %= b;
+    assert_eq!(x, a % b);
+}
+
+#[quickcheck]
+fn assign_ops_match_binary(a: i64, b: i64) -> bool {
+    if b == 0 {
+        return true;
+    }
+    let (x, y) = (Int128::from_i128(a as i128), Int128::from_i128(b as i128));
+    let mut z = x;
+    z += y;
+    z -= y;
+    z *= y;
+    z /= y;
+    z %= y;
+    z == ((((x + y) - y) * y) / y) % y
+}
+
+#[quickcheck]
+fn uint256_bit_assign_ops(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, n: u8) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m1, l3: m0 };
+    let n = n as u32;
+    let mut x = a;
+    x &= b;
+    let and_ok = x == a & b;
+    x |= a;
+    let or_ok = x == (a & b) | a;
+    x ^= b;
+    let xor_ok = x == ((a & b) | a) ^ b;
+    let mut y = a;
+    y <<= n;
+    let shl_ok = y == a << n;
+    y >>= n;
+    and_ok && or_ok && xor_ok && shl_ok && y == (a << n) >> n
+}
+
+#[quickcheck]
+fn int256_bit_assign_ops(a: i128, b: i128, n: u8) -> bool {
+    let (x, y) = (Int256::from_i128(a), Int256::from_i128(b));
+    let n = n as u32;
+    let mut z = x;
+    z &= y;
+    let and_ok = z == x & y;
+    z |= x;
+    let or_ok = z == (x & y) | x;
+    z ^= y;
+    let xor_ok = z == ((x & y) | x) ^ y;
+    let mut w = x;
+    w >>= n;
+    let shr_ok = w == x >> n;
+    w <<= n;
+    and_ok && or_ok && xor_ok && shr_ok && w == (x >> n) << n
+}
+
+/// Sums through `&T + &T`, so it only compiles if the reference impls exist.
+fn sum_refs<T: Copy>(xs: &[T], zero: T) -> T
+where
+    for<'a> &'a T: std::ops::Add<&'a T, Output = T>,
+{
+    xs.iter().fold(zero, |acc, x| &acc + x)
+}
+
+#[quickcheck]
+// References are the point of this test
+#[allow(clippy::op_ref)]
+fn uint256_ref_ops(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+    let arith = &a + &b == a + b && &a - b == a - b && a * &b == a * b;
+    let bits = &a & &b == a & b && &a | b == a | b && a ^ &b == a ^ b;
+    let div = b.is_zero() || (&a / &b == a / b && &a % &b == a % b);
+    arith && bits && div && sum_refs(&[a, b], Uint256::ZERO) == a + b
+}
+
+#[quickcheck]
+#[allow(clippy::op_ref)]
+fn int256_ref_ops(a: i128, b: i128) -> bool {
+    let (x, y) = (Int256::from_i128(a), Int256::from_i128(b));
+    let arith = &x + &y == x + y && &x - y == x - y && x * &y == x * y;
+    let bits = &x & &y == x & y && &x | y == x | y && x ^ &y == x ^ y;
+    let div = y.is_zero() || (&x / &y == x / y && &x % y == x % y);
+    arith && bits && div && sum_refs(&[x, y], Int256::ZERO) == x + y
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn ref_ops_small_types() {
+    let (a, b) = (Uint64::from_u64(100), Uint64::from_u64(7));
+    assert_eq!((&a % &b).to_u64(), 2);
+    let (c, d) = (Int64::from_i64(-100), Int64::from_i64(7));
+    assert_eq!((&c / d).to_i64(), -14);
+    let (e, f) = (from_native_u128(u128::MAX), from_native_u128(2));
+    assert_eq!(to_native_u128(e - &f), u128::MAX - 2);
+    let (g, h) = (Int128::from_i128(-5), Int128::from_i128(3));
+    assert_eq!((&g * &h).to_i128(), -15);
+    assert_eq!(sum_refs(&[a, b], Uint64::ZERO).to_u64(), 107);
+}
+
+#[quickcheck]
+fn power_of_two_small(a: u64, b: u128, shift: u8) -> bool {
+    // Shift right so small values, where rounding up doesn't overflow, are common
+    let a = a >> (shift % 64);
+    let b = b >> (shift % 128);
+    let (ua, ub) = (Uint64::from_u64(a), from_native_u128(b));
+    ua.is_power_of_two() == a.is_power_of_two()
+        && ub.is_power_of_two() == b.is_power_of_two()
+        && ub.leading_zeros() == b.leading_zeros()
+        && ua.next_power_of_two().to_u64() == a.checked_next_power_of_two().unwrap_or(0)
+        && to_native_u128(ub.next_power_of_two()) == b.checked_next_power_of_two().unwrap_or(0)
+}
+
+#[quickcheck]
+fn uint256_power_of_two(l0: u64, l1: u64, l2: u64, l3: u64, shift: u8) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 } >> (shift as u32);
+    let e = to_ethnum(&a);
+    let expected = e.checked_next_power_of_two().unwrap_or(ethnum::U256::ZERO);
+    a.is_power_of_two() == e.is_power_of_two() && a.next_power_of_two() == from_ethnum(expected)
+}
+
+#[test]
+fn uint256_power_of_two_edges() {
+    let top = Uint256::ONE << 255u32;
+    assert!(top.is_power_of_two());
+    assert!(!Uint256::MAX.is_power_of_two());
+    assert!(!Uint256::ZERO.is_power_of_two());
+    assert_eq!(Uint256::ZERO.next_power_of_two(), Uint256::ONE);
+    assert_eq!(top.next_power_of_two(), top);
+    assert_eq!((top - Uint256::ONE).next_power_of_two(), top);
+    assert_eq!((top + Uint256::ONE).next_power_of_two(), Uint256::ZERO);
+    assert_eq!(Uint256::MAX.next_power_of_two(), Uint256::ZERO);
+}
+
+#[quickcheck]
+fn uint256_ilog_u128(v: u128, base: u8) -> bool {
+    if v == 0 {
+        return true;
+    }
+    let base = (base as u128).max(2);
+    let a = Uint256::from_u128(v);
+    a.ilog2() == v.ilog2() && a.ilog10() == v.ilog10() && a.ilog(Uint256::from_u128(base)) == v.ilog(base)
+}
+
+#[quickcheck]
+fn uint256_ilog10(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    a.is_zero() || a.ilog10() as usize == a.to_string().len() - 1
+}
+
+#[test]
+fn uint256_ilog_edges() {
+    assert_eq!((Uint256::ONE << 200u32).ilog2(), 200);
+    assert_eq!(Uint256::MAX.ilog2(), 255);
+    assert_eq!(Uint256::ONE.ilog10(), 0);
+    // MAX is about 1.16e77
+    assert_eq!(Uint256::MAX.ilog10(), 77);
+    assert_eq!(Uint256::MAX.ilog(Uint256::MAX), 1);
+    assert_eq!((Uint256::MAX - Uint256::ONE).ilog(Uint256::MAX), 0);
+    assert_eq!(Uint256::MAX.ilog(Uint256::ONE << 128), 1);
+    assert_eq!(Uint256::MAX.ilog(Uint256::from(2u8)), 255);
+}
+
+#[test]
+#[should_panic(expected = "argument of integer logarithm must be positive")]
+fn uint256_ilog2_zero() {
+    let _ = Uint256::ZERO.ilog2();
+}
+
+#[test]
+#[should_panic(expected = "base of the integer logarithm must be at least 2")]
+fn uint256_ilog_base_one() {
+    let _ = Uint256::MAX.ilog(Uint256::ONE);
+}
+
+#[quickcheck]
+fn uint256_gcd(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, twos: u8) -> bool {
+    // Shared factors of two and a shared odd factor make nontrivial gcds common
+    let k = Uint256::from(m1 | 1) << (twos as u32 % 64);
+    let a = Uint256 { l0, l1, l2: 0, l3: 0 } * k;
+    let b = Uint256 { l0: l2, l1: l3, l2: 0, l3: 0 } * k + Uint256::from(m0) * k;
+    let g = a.gcd(b);
+    if g.is_zero() {
+        return a.is_zero() && b.is_zero();
+    }
+    let (ea, eb, eg) = (to_ethnum(&a), to_ethnum(&b), to_ethnum(&g));
+    ea % eg == ethnum::U256::ZERO && eb % eg == ethnum::U256::ZERO && g == b.gcd(a)
+}
+
+#[quickcheck]
+fn uint256_gcd_u128(a: u128, b: u128) -> bool {
+    fn gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+    Uint256::from_u128(a).gcd(Uint256::from_u128(b)) == Uint256::from_u128(gcd(a, b))
+}
+
+#[test]
+fn uint256_gcd_edges() {
+    let x = Uint256::MAX;
+    assert_eq!(x.gcd(Uint256::ZERO), x);
+    assert_eq!(Uint256::ZERO.gcd(x), x);
+    assert_eq!(Uint256::ZERO.gcd(Uint256::ZERO), Uint256::ZERO);
+    assert_eq!((Uint256::ONE << 255u32).gcd(Uint256::ONE << 100u32), Uint256::ONE << 100u32);
+    // 2^256 - 1 is divisible by 3 and 5
+    assert_eq!(x.gcd(Uint256::from(15u8)), Uint256::from(15u8));
+}
+
+#[quickcheck]
+fn checked_div_rem_small(a: u64, b: u64, c: i64, d: i64) -> bool {
+    let (ua, ub) = (Uint64::from_u64(a), Uint64::from_u64(b));
+    let (ic, id) = (Int64::from_i64(c), Int64::from_i64(d));
+    ua.checked_div(ub).map(Uint64::to_u64) == a.checked_div(b)
+        && ua.checked_rem(ub).map(Uint64::to_u64) == a.checked_rem(b)
+        && ic.checked_div(id).map(Int64::to_i64) == c.checked_div(d)
+        && ic.checked_rem(id).map(Int64::to_i64) == c.checked_rem(d)
+}
+
+#[quickcheck]
+fn checked_div_rem_128(a: u128, b: u128, c: i128, d: i128) -> bool {
+    let (ua, ub) = (from_native_u128(a), from_native_u128(b));
+    let (ic, id) = (Int128::from_i128(c), Int128::from_i128(d));
+    ua.checked_div(ub).map(to_native_u128) == a.checked_div(b)
+        && ua.checked_rem(ub).map(to_native_u128) == a.checked_rem(b)
+        && ic.checked_div(id).map(Int128::to_i128) == c.checked_div(d)
+        && ic.checked_rem(id).map(Int128::to_i128) == c.checked_rem(d)
+}
+
+#[quickcheck]
+fn checked_div_rem_256(a: i128, b: i128) -> bool {
+    let (x, y) = (Int256::from_i128(a), Int256::from_i128(b));
+    let (ux, uy) = (Uint256::from_u128(a as u128), Uint256::from_u128(b as u128));
+    // i128::MIN / -1 overflows i128 but not Int256
+    let signed_ok = (a == i128::MIN && b == -1)
+        || (x.checked_div(y).map(Int256::to_i128) == a.checked_div(b)
+            && x.checked_rem(y).map(Int256::to_i128) == a.checked_rem(b));
+    let unsigned_ok = ux.checked_div(uy).map(Uint256::to_u128) == (a as u128).checked_div(b as u128)
+        && ux.checked_rem(uy).map(Uint256::to_u128) == (a as u128).checked_rem(b as u128);
+    signed_ok && unsigned_ok
+}
+
+#[test]
+fn checked_div_rem_edges() {
+    assert_eq!(Uint256::MAX.checked_div(Uint256::ZERO), None);
+    assert_eq!(Uint256::MAX.checked_rem(Uint256::ZERO), None);
+    assert_eq!(Int256::MIN.checked_div(Int256::NEG_ONE), None);
+    assert_eq!(Int256::MIN.checked_rem(Int256::NEG_ONE), None);
+    assert_eq!(Int256::MIN.checked_div(Int256::ONE), Some(Int256::MIN));
+    assert_eq!(Int256::ONE.checked_div(Int256::ZERO), None);
+    assert_eq!(Int128::MIN.checked_div(Int128::NEG_ONE), None);
+    assert_eq!(Int64::MIN.checked_rem(Int64::NEG_ONE), None);
+    assert_eq!(Uint128::ONE.checked_div(Uint128::ZERO), None);
+    assert_eq!(Uint64::ONE.checked_rem(Uint64::ZERO), None);
+}
+
+#[quickcheck]
+fn uint128_mul_u64_wide(a: u64, b: u64) -> bool {
+    let product = (a as u128) * (b as u128);
+    Uint128::mul_u64_wide(a, b) == ((product >> 64) as u64, product as u64)
+}
+
+/// SplitMix64, enough of an RNG to drive the `rand` impls without pulling in
+/// a generator crate.
+#[cfg(feature = "rand")]
+struct SplitMix(u64);
+
+#[cfg(feature = "rand")]
+impl rand::RngCore for SplitMix {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+#[quickcheck]
+fn uint256_random_below(seed: u64, l0: u64, l1: u64, l2: u64, l3: u64, shift: u8) -> bool {
+    let bound = Uint256 { l0, l1, l2, l3 } >> (shift as u32);
+    if bound.is_zero() {
+        return true;
+    }
+    let mut rng = SplitMix(seed);
+    (0..16).all(|_| Uint256::random_below(&mut rng, bound) < bound)
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_full_range() {
+    use rand::Rng;
+    let mut rng = SplitMix(1);
+    let values: Vec<Uint256> = (0..64).map(|_| rng.r#gen()).collect();
+    assert!(values.iter().any(|v| v.l3 >> 63 == 1));
+    assert!(values.iter().any(|v| v.l3 >> 63 == 0));
+    let signed: Vec<Int256> = (0..64).map(|_| rng.r#gen()).collect();
+    assert!(signed.iter().any(Int256::is_negative));
+    let _: (Uint64, Int64, Uint128, Int128) = rng.r#gen();
+    assert_eq!(Uint256::random_below(&mut rng, Uint256::ONE), Uint256::ZERO);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic(expected = "cannot sample empty range")]
+fn random_below_zero() {
+    let _ = Uint256::random_below(&mut SplitMix(0), Uint256::ZERO);
+}
+
+#[cfg(feature = "num-traits")]
+fn checked_sum<T: num_traits::Zero + num_traits::CheckedAdd>(xs: &[T]) -> Option<T> {
+    xs.iter().try_fold(T::zero(), |acc, x| acc.checked_add(x))
+}
+
+#[cfg(feature = "num-traits")]
+fn wrapping_product<T: num_traits::One + num_traits::WrappingMul>(xs: &[T]) -> T {
+    xs.iter().fold(T::one(), |acc, x| acc.wrapping_mul(x))
+}
+
+#[cfg(feature = "num-traits")]
+fn bounds_ordered<T: num_traits::Bounded + num_traits::Zero + num_traits::One + PartialOrd>() -> bool {
+    T::min_value() <= T::zero() && T::zero() < T::one() && T::one() <= T::max_value()
+}
+
+#[cfg(feature = "num-traits")]
+#[quickcheck]
+fn uint256_num_traits_checked_sum(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let expected = to_ethnum(&a).checked_add(to_ethnum(&b)).map(from_ethnum);
+    checked_sum(&[a, b]) == expected
+}
+
+#[cfg(feature = "num-traits")]
+#[quickcheck]
+fn int128_num_traits_wrapping_product(a: i128, b: i128, c: i128) -> bool {
+    let xs = [Int128::from_i128(a), Int128::from_i128(b), Int128::from_i128(c)];
+    wrapping_product(&xs).to_i128() == a.wrapping_mul(b).wrapping_mul(c)
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn num_traits_bounds() {
+    assert!(bounds_ordered::<Uint64>());
+    assert!(bounds_ordered::<Int64>());
+    assert!(bounds_ordered::<Uint128>());
+    assert!(bounds_ordered::<Int128>());
+    assert!(bounds_ordered::<Uint256>());
+    assert!(bounds_ordered::<Int256>());
+    assert_eq!(checked_sum(&[Uint64::MAX, Uint64::ONE]), None);
+    assert_eq!(checked_sum(&[Int256::MAX, Int256::ONE]), None);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn num_traits_from_str_radix() {
+    use num_traits::Num;
+    assert_eq!(<Uint64 as Num>::from_str_radix("ff", 16), Ok(Uint64::new(255, 0)));
+    assert_eq!(<Int64 as Num>::from_str_radix("-1", 10), Ok(Int64::new(u32::MAX, u32::MAX)));
+    assert_eq!(<Uint128 as Num>::from_str_radix("101", 2), Ok(Uint128::from(5u8)));
+    assert_eq!(<Int128 as Num>::from_str_radix("-10", 8), Ok(Int128::from_i128(-8)));
+    assert_eq!(<Uint256 as Num>::from_str_radix("z", 36), Ok(Uint256::from(35u8)));
+    assert_eq!(<Int256 as Num>::from_str_radix("-7f", 16), Ok(Int256::from(-127i128)));
+    assert_eq!(
+        <Uint64 as Num>::from_str_radix("-1", 10).unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        <Int128 as Num>::from_str_radix("", 10).unwrap_err().kind(),
+        &IntErrorKind::Empty
+    );
+    assert_eq!(
+        <Uint128 as Num>::from_str_radix(&"f".repeat(33), 16).unwrap_err().kind(),
+        &IntErrorKind::PosOverflow
+    );
+}
+
+#[quickcheck]
+fn biguint4_add_sub_match_uint256(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let (ga, gb) = (BigUint::<4>::from(a), BigUint::<4>::from(b));
+    let (sum, carry) = ga.overflowing_add(gb);
+    let (diff, borrow) = ga.overflowing_sub(gb);
+    (Uint256::from(sum), carry) == a.overflowing_add(b)
+        && (Uint256::from(diff), borrow) == a.overflowing_sub(b)
+}
+
+#[quickcheck]
+fn biguint4_mul_matches_uint256(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64), shift: u8) -> bool {
+    // Shift one operand down so the non-overflowing path is exercised too.
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 } >> (shift as u32);
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let (product, overflow) = BigUint::<4>::from(a).overflowing_mul(BigUint::from(b));
+    (Uint256::from(product), overflow) == a.overflowing_mul(b)
+}
+
+#[quickcheck]
+fn biguint4_cmp_matches_uint256(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    BigUint::<4>::from(a).cmp(&BigUint::from(b)) == a.cmp(&b)
+}
+
+#[quickcheck]
+fn biguint8_mul_matches_uint256_widening(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let widen = |u: Uint256| BigUint::<8>([u.l0, u.l1, u.l2, u.l3, 0, 0, 0, 0]);
+    let (hi, lo) = a.widening_mul(b);
+    let expected = BigUint([lo.l0, lo.l1, lo.l2, lo.l3, hi.l0, hi.l1, hi.l2, hi.l3]);
+    widen(a).checked_mul(widen(b)) == Some(expected)
+}
+
+#[test]
+fn biguint_edges() {
+    type U512 = BigUint<8>;
+    assert_eq!(U512::MAX + U512::ONE, U512::ZERO);
+    assert_eq!(U512::ZERO - U512::ONE, U512::MAX);
+    assert_eq!(U512::MAX.checked_mul(U512::MAX), None);
+    assert_eq!(U512::MAX * U512::MAX, U512::ONE);
+    assert!(U512::ONE > U512::ZERO);
+    assert_eq!(U512::BITS, 512);
+    assert_eq!(BigUint::<3>::default(), BigUint::<3>::ZERO);
+}
+
+fn u512_from_tuple(t: (u64, u64, u64, u64, u64, u64, u64, u64)) -> Uint512 {
+    Uint512 { l0: t.0, l1: t.1, l2: t.2, l3: t.3, l4: t.4, l5: t.5, l6: t.6, l7: t.7 }
+}
+
+#[quickcheck]
+fn uint512_add_sub_match_uint256(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let (sum, carry) = a.overflowing_add(b);
+    let wide_sum = Uint512::from(a) + Uint512::from(b);
+    let (diff, borrow) = a.overflowing_sub(b);
+    let wide_diff = Uint512::from(a) - Uint512::from(b);
+    let diff_hi = if borrow { Uint256::MAX } else { Uint256::ZERO };
+    wide_sum == Uint512::from_halves(Uint256::from(carry as u8), sum)
+        && wide_diff == Uint512::from_halves(diff_hi, diff)
+}
+
+#[quickcheck]
+fn uint512_mul_matches_widening(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let (hi, lo) = a.widening_mul(b);
+    Uint512::from(a).checked_mul(Uint512::from(b)) == Some(Uint512::from_halves(hi, lo))
+}
+
+#[quickcheck]
+fn uint512_div_rem_inverts_widening(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64), c: u64) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    if b.is_zero() {
+        return true;
+    }
+    let r = Uint256::from(c).min(b - Uint256::ONE);
+    let (hi, lo) = a.widening_mul(b);
+    let n = Uint512::from_halves(hi, lo) + Uint512::from(r);
+    n.div_rem(Uint512::from(b)) == (Uint512::from(a), Uint512::from(r))
+}
+
+#[quickcheck]
+fn uint512_div_rem_reconstructs(
+    n: (u64, u64, u64, u64, u64, u64, u64, u64),
+    d: (u64, u64, u64, u64, u64, u64, u64, u64),
+) -> bool {
+    let n = u512_from_tuple(n);
+    // Vary the divisor length using bits of its own low limb.
+    let d = u512_from_tuple(d) >> (d.0 as u32 % 512);
+    if d.is_zero() {
+        return true;
+    }
+    let (q, r) = n.div_rem(d);
+    r < d && q.checked_mul(d).and_then(|p| p.checked_add(r)) == Some(n)
+}
+
+#[test]
+fn uint512_edges() {
+    assert_eq!(Uint512::MAX + Uint512::ONE, Uint512::ZERO);
+    assert_eq!(Uint512::MAX.checked_mul(Uint512::from(2u64)), None);
+    assert_eq!(Uint512::MAX.leading_zeros(), 0);
+    assert_eq!(Uint512::ZERO.leading_zeros(), 512);
+    assert_eq!((Uint512::ONE << 511) >> 511, Uint512::ONE);
+    assert_eq!(Uint512::MAX / Uint512::MAX, Uint512::ONE);
+    assert_eq!(Uint512::ONE.checked_div(Uint512::ZERO), None);
+    assert_eq!(
+        Uint512::MAX.to_string(),
+        "13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946569946433649006084095"
+    );
+    assert_eq!(Uint512::from(Uint256::MAX).to_string(), Uint256::MAX.to_string());
+    assert_eq!(Uint256::try_from(Uint512::from(Uint256::MAX)), Ok(Uint256::MAX));
+    assert!(Uint256::try_from(Uint512::ONE << 256).is_err());
+}
+
+#[quickcheck]
+fn int512_mul_div_roundtrip(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let a = Int512::from(Int256::new(a.0, a.1, a.2, a.3));
+    let b = Int512::from(Int256::new(b.0, b.1, b.2, b.3));
+    if b.is_zero() {
+        return true;
+    }
+    // Products of 256-bit values never overflow 512 bits.
+    let p = a.checked_mul(b).unwrap();
+    p / b == a && p % b == Int512::ZERO
+}
+
+#[quickcheck]
+fn int512_matches_int256(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64), shift: u8) -> bool {
+    let a = Int256::new(a.0, a.1, a.2, a.3);
+    let b = Int256::new(b.0, b.1, b.2, b.3);
+    let (wa, wb) = (Int512::from(a), Int512::from(b));
+    let shift = shift as u32;
+    let div_ok = b.is_zero()
+        || (a == Int256::MIN && b == Int256::from(-1i128))
+        || (wa / wb == Int512::from(a / b) && wa % wb == Int512::from(a % b));
+    wa.cmp(&wb) == a.cmp(&b)
+        && wa >> shift == Int512::from(a >> shift)
+        && (wa + wb) - wb == wa
+        && div_ok
+        && Int256::try_from(wa) == Ok(a)
+        && wa.to_string() == a.to_string()
+}
+
+#[test]
+fn int512_edges() {
+    assert_eq!(Int512::MAX + Int512::ONE, Int512::MIN);
+    assert_eq!(Int512::MIN.checked_sub(Int512::ONE), None);
+    assert_eq!(Int512::MIN.checked_div(Int512::NEG_ONE), None);
+    assert_eq!(Int512::MIN.checked_abs(), None);
+    assert!(Int512::MIN < Int512::NEG_ONE && Int512::NEG_ONE < Int512::ZERO);
+    assert_eq!(Int512::MIN >> 511, Int512::NEG_ONE);
+    assert_eq!(Int512::from(-7i128) / Int512::from(2i128), Int512::from(-3i128));
+    assert_eq!(Int512::from(-7i128) % Int512::from(2i128), Int512::from(-1i128));
+    assert!(Int256::try_from(Int512::MIN).is_err());
+    assert!(Uint512::try_from(Int512::NEG_ONE).is_err());
+    assert!(Int512::try_from(Uint512::MAX).is_err());
+    assert_eq!(Int512::MIN.to_string().len(), 155);
+}
+
+#[quickcheck]
+fn uint256_ct_eq_select(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64), same: bool) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = if same { a } else { Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 } };
+    a.ct_eq(&b) == (a == b) as u8
+        && Uint256::ct_select(1, a, b) == a
+        && Uint256::ct_select(0, a, b) == b
+        && Uint256::ct_select(a.ct_eq(&b), Uint256::ONE, Uint256::ZERO) == Uint256::from((a == b) as u8)
+}
+
+#[test]
+fn uint256_ct_eq_single_bit() {
+    // A difference in any one bit position must be detected.
+    for i in 0..256u32 {
+        let x = Uint256::ONE << i;
+        assert_eq!(x.ct_eq(&x), 1);
+        assert_eq!(x.ct_eq(&Uint256::ZERO), 0);
+        assert_eq!(Uint256::MAX.ct_eq(&(Uint256::MAX ^ x)), 0);
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[quickcheck]
+fn uint256_subtle_traits(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64), choice: bool) -> bool {
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let picked = Uint256::conditional_select(&a, &b, Choice::from(choice as u8));
+    bool::from(ConstantTimeEq::ct_eq(&a, &a))
+        && bool::from(ConstantTimeEq::ct_eq(&a, &b)) == (a == b)
+        && picked == if choice { b } else { a }
+}
+
+#[quickcheck]
+fn bits_match_native(a: u64, b: i64, c: u128, d: i128) -> bool {
+    Uint64::from_u64(a).bits() == u64::BITS - a.leading_zeros()
+        && Int64::from_i64(b).bits() == i64::BITS - b.leading_zeros()
+        && from_native_u128(c).bits() == u128::BITS - c.leading_zeros()
+        && Int128::from_i128(d).bits() == i128::BITS - d.leading_zeros()
+        && Int256::from_i128(d).bits() == if d < 0 { 256 } else { i128::BITS - d.leading_zeros() }
+}
+
+#[quickcheck]
+fn uint256_bits(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    a.bits() == 256 - to_ethnum(&a).leading_zeros()
+}
+
+#[test]
+fn bits_edges() {
+    assert_eq!(Uint64::ZERO.bits(), 0);
+    assert_eq!(Uint128::ZERO.bits(), 0);
+    assert_eq!(Uint256::ZERO.bits(), 0);
+    assert_eq!(Int64::ZERO.bits(), 0);
+    assert_eq!(Int128::ZERO.bits(), 0);
+    assert_eq!(Int256::ZERO.bits(), 0);
+    assert_eq!(Uint64::ONE.bits(), 1);
+    assert_eq!(Uint128::ONE.bits(), 1);
+    assert_eq!(Uint256::ONE.bits(), 1);
+    assert_eq!(Int256::ONE.bits(), 1);
+    assert_eq!(Uint64::MAX.bits(), 64);
+    assert_eq!(Uint128::MAX.bits(), 128);
+    assert_eq!(Uint256::MAX.bits(), 256);
+    assert_eq!(Int64::MAX.bits(), 63);
+    assert_eq!(Int128::MAX.bits(), 127);
+    assert_eq!(Int256::MAX.bits(), 255);
+    assert_eq!(Int256::NEG_ONE.bits(), 256);
+    for i in 0..256u32 {
+        assert_eq!((Uint256::ONE << i).bits(), i + 1);
+    }
+    for i in 0..64 {
+        assert_eq!(Uint64::from_u64(1 << i).bits(), i + 1);
+    }
+}
+
+#[quickcheck]
+fn uint256_bit_ops(l0: u64, l1: u64, l2: u64, l3: u64, i: u8) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let i = i as u32;
+    let mask = Uint256::ONE << i;
+    let (mut set, mut clear, mut flip) = (a, a, a);
+    set.set_bit(i);
+    clear.clear_bit(i);
+    flip.flip_bit(i);
+    a.bit(i) != (a & mask).is_zero()
+        && set == a | mask
+        && clear == a & !mask
+        && flip == a ^ mask
+        && set.bit(i)
+        && !clear.bit(i)
+}
+
+#[test]
+fn uint256_bit_limb_boundaries() {
+    let mut x = Uint256::ZERO;
+    for i in [0, 63, 64, 127, 128, 191, 192, 255] {
+        x.set_bit(i);
+        assert!(x.bit(i));
+    }
+    assert_eq!(x.count_ones(), 8);
+    assert_eq!(x.l1, 1 | 1 << 63);
+    x.flip_bit(64);
+    x.clear_bit(255);
+    assert_eq!(x.l1, 1 << 63);
+    assert_eq!(x.l3, 1);
+    assert!(!x.bit(64) && !x.bit(255));
+}
+
+#[test]
+#[should_panic(expected = "bit index 256 out of range for Uint256")]
+fn uint256_bit_out_of_range() {
+    let _ = Uint256::ZERO.bit(256);
+}
+
+#[quickcheck]
+fn pow_small_types(a: u64, b: i64, c: u128, d: i128, exp: u8) -> bool {
+    let exp = exp as u32;
+    Uint64::from_u64(a).pow(exp) == Uint64::from_u64(a.wrapping_pow(exp))
+        && Int64::from_i64(b).pow(exp) == Int64::from_i64(b.wrapping_pow(exp))
+        && to_native_u128(from_native_u128(c).pow(exp)) == c.wrapping_pow(exp)
+        && Int128::from_i128(d).pow(exp).to_i128() == d.wrapping_pow(exp)
+}
+
+#[quickcheck]
+fn checked_pow_small_types(a: u16, b: i16, c: u32, d: i32, exp: u8) -> bool {
+    // Small bases so both the in-range and the overflowing cases come up.
+    let exp = exp as u32 % 40;
+    let (a, b, c, d) = (a as u64, b as i64, c as u128, d as i128);
+    Uint64::from_u64(a).checked_pow(exp) == a.checked_pow(exp).map(Uint64::from_u64)
+        && Int64::from_i64(b).checked_pow(exp) == b.checked_pow(exp).map(Int64::from_i64)
+        && from_native_u128(c).checked_pow(exp) == c.checked_pow(exp).map(from_native_u128)
+        && Int128::from_i128(d).checked_pow(exp) == d.checked_pow(exp).map(Int128::from_i128)
+}
+
+#[test]
+fn checked_pow_small_types_edges() {
+    assert_eq!(Int64::from_i64(-2).checked_pow(63), Some(Int64::MIN));
+    assert_eq!(Int64::from_i64(2).checked_pow(63), None);
+    assert_eq!(Int128::from_i128(-2).checked_pow(127), Some(Int128::MIN));
+    assert_eq!(Uint128::from(2u8).checked_pow(128), None);
+    assert_eq!(Uint64::ZERO.pow(0), Uint64::ONE);
+}
+
+#[quickcheck]
+fn unsigned_wrapping_neg(a: u64, b: u128, l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let c = Uint256 { l0, l1, l2, l3 };
+    Uint64::from_u64(a).wrapping_neg() == Uint64::from_u64(a.wrapping_neg())
+        && to_native_u128(from_native_u128(b).wrapping_neg()) == b.wrapping_neg()
+        && to_ethnum(&c.wrapping_neg()) == to_ethnum(&c).wrapping_neg()
+        && c.wrapping_neg() + c == Uint256::ZERO
+}
+
+#[quickcheck]
+fn wrapping_shifts_64(a: u64, b: i64, n: u32) -> bool {
+    Uint64::from_u64(a).wrapping_shl(n) == Uint64::from_u64(a.wrapping_shl(n))
+        && Uint64::from_u64(a).wrapping_shr(n) == Uint64::from_u64(a.wrapping_shr(n))
+        && Int64::from_i64(b).wrapping_shl(n) == Int64::from_i64(b.wrapping_shl(n))
+        && Int64::from_i64(b).wrapping_shr(n) == Int64::from_i64(b.wrapping_shr(n))
+}
+
+#[quickcheck]
+fn wrapping_shifts_128(a: u128, b: i128, n: u32) -> bool {
+    to_native_u128(from_native_u128(a).wrapping_shl(n)) == a.wrapping_shl(n)
+        && to_native_u128(from_native_u128(a).wrapping_shr(n)) == a.wrapping_shr(n)
+        && Int128::from_i128(b).wrapping_shl(n).to_i128() == b.wrapping_shl(n)
+        && Int128::from_i128(b).wrapping_shr(n).to_i128() == b.wrapping_shr(n)
+}
+
+#[quickcheck]
+fn wrapping_shifts_256(l0: u64, l1: u64, l2: u64, l3: u64, n: u32) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Int256::new(l0, l1, l2, l3);
+    a.wrapping_shl(n) == a << (n % 256)
+        && a.wrapping_shr(n) == a >> (n % 256)
+        && b.wrapping_shl(n) == b << (n % 256)
+        && b.wrapping_shr(n) == b >> (n % 256)
+}
+
+#[test]
+fn wrapping_shifts_mask_not_saturate() {
+    let x = Uint256::MAX;
+    assert_eq!(x.wrapping_shl(256), x.wrapping_shl(0));
+    assert_eq!(x.wrapping_shl(257), x << 1);
+    assert_eq!(x << 256, Uint256::ZERO);
+    assert_eq!(Int256::NEG_ONE.wrapping_shr(256), Int256::NEG_ONE);
+    assert_eq!(Int128::ONE.wrapping_shl(128), Int128::ONE);
+}
+
+#[quickcheck]
+fn checked_shifts_native(a: u64, b: i64, c: u128, d: i128, n: u8) -> bool {
+    // n ranges over 0..256, covering valid and oversized shifts for both widths.
+    let n = n as u32;
+    Uint64::from_u64(a).checked_shl(n) == a.checked_shl(n).map(Uint64::from_u64)
+        && Int64::from_i64(b).checked_shr(n) == b.checked_shr(n).map(Int64::from_i64)
+        && from_native_u128(c).checked_shr(n) == c.checked_shr(n).map(from_native_u128)
+        && Int128::from_i128(d).checked_shl(n) == d.checked_shl(n).map(Int128::from_i128)
+}
+
+#[test]
+fn checked_shifts_at_width() {
+    assert_eq!(Uint64::ONE.checked_shl(63), Some(Uint64::ONE << 63));
+    assert_eq!(Uint64::ONE.checked_shl(64), None);
+    assert_eq!(Int64::MIN.checked_shr(63), Some(Int64::from_i64(-1)));
+    assert_eq!(Int64::MIN.checked_shr(64), None);
+    assert_eq!(Uint128::MAX.checked_shr(127), Some(Uint128::ONE));
+    assert_eq!(Uint128::MAX.checked_shr(128), None);
+    assert_eq!(Int128::ONE.checked_shl(127), Some(Int128::MIN));
+    assert_eq!(Int128::ONE.checked_shl(128), None);
+    assert_eq!(Uint256::ONE.checked_shl(255), Some(Uint256::ONE << 255));
+    assert_eq!(Uint256::ONE.checked_shl(256), None);
+    assert_eq!(Int256::MIN.checked_shr(255), Some(Int256::NEG_ONE));
+    assert_eq!(Int256::MIN.checked_shr(256), None);
+    assert_eq!(Uint512::MAX.checked_shr(511), Some(Uint512::ONE));
+    assert_eq!(Int512::ONE.checked_shl(512), None);
+}
+
+#[quickcheck]
+fn shift_by_other_int_types(l0: u64, l1: u64, l2: u64, l3: u64, n: u16) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Int256::new(l0, l1, l2, l3);
+    let m = n as u32;
+    a << n as usize == a << m
+        && a >> n as u64 == a >> m
+        && a << n as u8 == a << (n as u8 as u32)
+        && b >> n as i64 == b >> m
+        && b << n as isize == b << m
+        && b >> (n >> 1) as i16 == b >> (m >> 1)
+}
+
+#[test]
+fn shift_by_usize() {
+    let bits: usize = 200;
+    assert_eq!((Uint256::ONE << bits) >> bits, Uint256::ONE);
+    assert_eq!(Int256::NEG_ONE >> bits, Int256::NEG_ONE);
+    assert_eq!(Uint256::MAX >> u128::MAX, Uint256::ZERO);
+    assert_eq!(Int256::ONE << 5i8, Int256::from_i128(32));
+}
+
+#[test]
+#[should_panic(expected = "attempt to shift left with overflow")]
+fn shift_by_negative_panics() {
+    let _ = Uint256::ONE << -1i32;
+}
+
+#[quickcheck]
+fn abs_diff_native(a: u64, b: u64, c: i64, d: i64, e: u128, f: u128) -> bool {
+    let (g, h) = (e as i128, f as i128);
+    Uint64::from_u64(a).abs_diff(Uint64::from_u64(b)) == Uint64::from_u64(a.abs_diff(b))
+        && Int64::from_i64(c).abs_diff(Int64::from_i64(d)) == Uint64::from_u64(c.abs_diff(d))
+        && from_native_u128(e).abs_diff(from_native_u128(f)) == from_native_u128(e.abs_diff(f))
+        && Int128::from_i128(g).abs_diff(Int128::from_i128(h)) == from_native_u128(g.abs_diff(h))
+        && Int256::from_i128(g).abs_diff(Int256::from_i128(h)) == Uint256::from_u128(g.abs_diff(h))
+}
+
+#[quickcheck]
+fn uint256_abs_diff(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+    let d = a.abs_diff(b);
+    d == b.abs_diff(a) && (a.max(b) - d == a.min(b))
+}
+
+#[test]
+fn abs_diff_extremes() {
+    assert_eq!(Int256::MIN.abs_diff(Int256::MAX), Uint256::MAX);
+    assert_eq!(Int64::MAX.abs_diff(Int64::MIN), Uint64::MAX);
+    assert_eq!(Int128::MIN.abs_diff(Int128::ZERO), from_native_u128(1 << 127));
+    assert_eq!(Uint256::ZERO.abs_diff(Uint256::MAX), Uint256::MAX);
+}
+
+#[quickcheck]
+fn div_rem_euclid_native(a: i64, b: i64, c: i128, d: i128) -> bool {
+    let small = match (a.checked_div_euclid(b), a.checked_rem_euclid(b)) {
+        (Some(q), Some(r)) => {
+            let (x, y) = (Int64::from_i64(a), Int64::from_i64(b));
+            x.div_euclid(y) == Int64::from_i64(q) && x.rem_euclid(y) == Int64::from_i64(r)
+        }
+        _ => true,
+    };
+    let wide = match (c.checked_div_euclid(d), c.checked_rem_euclid(d)) {
+        (Some(q), Some(r)) => {
+            let (x, y) = (Int128::from_i128(c), Int128::from_i128(d));
+            let (u, v) = (Int256::from_i128(c), Int256::from_i128(d));
+            x.div_euclid(y) == Int128::from_i128(q)
+                && x.rem_euclid(y) == Int128::from_i128(r)
+                && u.div_euclid(v) == Int256::from_i128(q)
+                && u.rem_euclid(v) == Int256::from_i128(r)
+        }
+        _ => true,
+    };
+    small && wide
+}
+
+#[quickcheck]
+fn signed_div_rem_native(a: i64, b: i64, c: i128, d: i128) -> bool {
+    let small = match (a.checked_div(b), a.checked_rem(b)) {
+        (Some(q), Some(r)) => {
+            Int64::from_i64(a).div_rem(Int64::from_i64(b)) == (Int64::from_i64(q), Int64::from_i64(r))
+        }
+        _ => true,
+    };
+    let wide = match (c.checked_div(d), c.checked_rem(d)) {
+        (Some(q), Some(r)) => {
+            Int128::from_i128(c).div_rem(Int128::from_i128(d)) == (Int128::from_i128(q), Int128::from_i128(r))
+                && Int256::from_i128(c).div_rem(Int256::from_i128(d)) == (Int256::from_i128(q), Int256::from_i128(r))
+        }
+        _ => true,
+    };
+    small && wide
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn int256_div_rem_overflow() {
+    let _ = Int256::MIN.div_rem(Int256::NEG_ONE);
+}
+
+#[quickcheck]
+fn int256_div_rem_euclid_identity(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let x = Int256::new(a.0, a.1, a.2, a.3);
+    let y = Int256::new(b.0, b.1, b.2, b.3);
+    if y.is_zero() || (x == Int256::MIN && y == Int256::NEG_ONE) {
+        return true;
+    }
+    let (q, r) = (x.div_euclid(y), x.rem_euclid(y));
+    !r.is_negative() && r.to_uint256() < y.abs_diff(Int256::ZERO) && q * y + r == x
+}
+
+#[test]
+fn div_rem_euclid_edges() {
+    assert_eq!(Int256::MIN.div_euclid(Int256::MAX), Int256::NEG_ONE - Int256::ONE);
+    assert_eq!(Int256::MIN.rem_euclid(Int256::MAX), Int256::MAX - Int256::ONE);
+    assert_eq!(Int256::MIN.rem_euclid(Int256::MIN), Int256::ZERO);
+    assert_eq!(Int256::from_i128(-7).rem_euclid(Int256::MIN), Int256::MAX - Int256::from_i128(6));
+    assert_eq!(Int128::MIN.div_euclid(Int128::from_i128(3)), Int128::from_i128(i128::MIN.div_euclid(3)));
+    assert_eq!(Int64::MIN.rem_euclid(Int64::from_i64(-3)), Int64::from_i64(i64::MIN.rem_euclid(-3)));
+}
+
+#[quickcheck]
+fn checked_div_rem_euclid_native(a: i64, b: i64, c: i128, d: i128, special: u8) -> bool {
+    // Force the zero divisor and MIN / -1 cases a fair share of the time.
+    let (b, c, d) = match special % 4 {
+        0 => (0, c, 0),
+        1 => (-1, c, -1),
+        2 => (b, i128::MIN, -1),
+        _ => (b, c, d),
+    };
+    let a = if special % 4 == 1 { i64::MIN } else { a };
+    let (x, y) = (Int64::from_i64(a), Int64::from_i64(b));
+    let (u, v) = (Int128::from_i128(c), Int128::from_i128(d));
+    let (p, q) = (Int256::from_i128(c), Int256::from_i128(d));
+    // i128::MIN / -1 overflows i128 but not Int256.
+    let (wide_q, wide_r) = if c == i128::MIN && d == -1 {
+        (Some(p.wrapping_neg()), Some(Int256::ZERO))
+    } else {
+        (c.checked_div_euclid(d).map(Int256::from_i128), c.checked_rem_euclid(d).map(Int256::from_i128))
+    };
+    x.checked_div_euclid(y) == a.checked_div_euclid(b).map(Int64::from_i64)
+        && x.checked_rem_euclid(y) == a.checked_rem_euclid(b).map(Int64::from_i64)
+        && u.checked_div_euclid(v) == c.checked_div_euclid(d).map(Int128::from_i128)
+        && u.checked_rem_euclid(v) == c.checked_rem_euclid(d).map(Int128::from_i128)
+        && p.checked_div_euclid(q) == wide_q
+        && p.checked_rem_euclid(q) == wide_r
+}
+
+#[test]
+fn checked_div_rem_euclid_edges() {
+    assert_eq!(Int256::MIN.checked_div_euclid(Int256::NEG_ONE), None);
+    assert_eq!(Int256::MIN.checked_rem_euclid(Int256::NEG_ONE), None);
+    assert_eq!(Int256::ONE.checked_div_euclid(Int256::ZERO), None);
+    assert_eq!(Int256::ONE.checked_rem_euclid(Int256::ZERO), None);
+    assert_eq!(Int256::MIN.checked_div_euclid(Int256::ONE), Some(Int256::MIN));
+    assert_eq!(
+        Int256::from_i128(-7).checked_rem_euclid(Int256::from_i128(-3)),
+        Some(Int256::from_i128(2))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn int256_div_euclid_min_by_neg_one() {
+    let _ = Int256::MIN.div_euclid(Int256::NEG_ONE);
+}
+
+#[quickcheck]
+fn signed_neg_variants_native(a: i64, b: i128) -> bool {
+    let x = Int64::from_i64(a);
+    let y = Int128::from_i128(b);
+    let z = Int256::from_i128(b);
+    x.checked_neg() == a.checked_neg().map(Int64::from_i64)
+        && x.overflowing_neg() == (Int64::from_i64(a.wrapping_neg()), a == i64::MIN)
+        && x.wrapping_neg() == Int64::from_i64(a.wrapping_neg())
+        && y.checked_neg() == b.checked_neg().map(Int128::from_i128)
+        && y.overflowing_neg() == (Int128::from_i128(b.wrapping_neg()), b == i128::MIN)
+        && y.wrapping_neg() == Int128::from_i128(b.wrapping_neg())
+        && z.checked_neg() == Some(Int256::ZERO - z)
+}
+
+#[test]
+fn signed_neg_variants_edges() {
+    assert_eq!(Int256::MIN.checked_neg(), None);
+    assert_eq!(Int256::MIN.overflowing_neg(), (Int256::MIN, true));
+    assert_eq!(Int256::MIN.wrapping_neg(), Int256::MIN);
+    assert_eq!(Int256::ZERO.checked_neg(), Some(Int256::ZERO));
+    assert_eq!(Int256::MAX.checked_neg(), Some(Int256::MIN + Int256::ONE));
+    assert_eq!(Int256::MAX.overflowing_neg(), (Int256::MIN + Int256::ONE, false));
+    assert_eq!(Int128::MIN.checked_neg(), None);
+    assert_eq!(Int64::MIN.overflowing_neg(), (Int64::MIN, true));
+    assert_eq!(Int64::ZERO.wrapping_neg(), Int64::ZERO);
+}
+
+#[quickcheck]
+fn try_to_128_roundtrip(a: i128, b: u128) -> bool {
+    Int256::from_i128(a).try_to_i128() == Some(a)
+        && Int256::from_i128(a).to_i128_saturating() == a
+        && Uint256::from_u128(b).try_to_u128() == Some(b)
+        && Uint256::from_u128(b).to_u128_saturating() == b
+}
+
+#[quickcheck]
+fn try_to_128_matches_try_from(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let s = Int256::new(l0, l1, l2, l3);
+    let u = Uint256 { l0, l1, l2, l3 };
+    s.try_to_i128() == Int128::try_from(s).ok().map(Int128::to_i128)
+        && u.try_to_u128() == Uint128::try_from(u).ok().map(to_native_u128)
+}
+
+#[test]
+fn try_to_128_boundaries() {
+    let i_max = Int256::from_i128(i128::MAX);
+    let i_min = Int256::from_i128(i128::MIN);
+    assert_eq!(i_max.try_to_i128(), Some(i128::MAX));
+    assert_eq!(i_min.try_to_i128(), Some(i128::MIN));
+    assert_eq!((i_max + Int256::ONE).try_to_i128(), None);
+    assert_eq!((i_min - Int256::ONE).try_to_i128(), None);
+    assert_eq!((i_max + Int256::ONE).to_i128_saturating(), i128::MAX);
+    assert_eq!((i_min - Int256::ONE).to_i128_saturating(), i128::MIN);
+    assert_eq!(Int256::MAX.to_i128_saturating(), i128::MAX);
+    assert_eq!(Int256::MIN.to_i128_saturating(), i128::MIN);
+    assert_eq!(Int256::NEG_ONE.try_to_i128(), Some(-1));
+
+    let u_max = Uint256::from_u128(u128::MAX);
+    assert_eq!(u_max.try_to_u128(), Some(u128::MAX));
+    assert_eq!((u_max + Uint256::ONE).try_to_u128(), None);
+    assert_eq!((u_max + Uint256::ONE).to_u128_saturating(), u128::MAX);
+    assert_eq!(Uint256::MAX.to_u128_saturating(), u128::MAX);
+}
+
+#[quickcheck]
+fn uint256_carrying_add_borrowing_sub_chain(
+    a: (u64, u64, u64, u64, u64, u64, u64, u64),
+    b: (u64, u64, u64, u64, u64, u64, u64, u64),
+) -> bool {
+    let (x, y) = (u512_from_tuple(a), u512_from_tuple(b));
+    let ((xh, xl), (yh, yl)) = (x.split(), y.split());
+
+    let (sl, c) = xl.carrying_add(yl, false);
+    let (sh, c) = xh.carrying_add(yh, c);
+    let (dl, b) = xl.borrowing_sub(yl, false);
+    let (dh, b) = xh.borrowing_sub(yh, b);
+
+    (Uint512::from_halves(sh, sl), c) == x.overflowing_add(y)
+        && (Uint512::from_halves(dh, dl), b) == x.overflowing_sub(y)
+}
+
+#[test]
+fn uint256_carrying_add_borrowing_sub_edges() {
+    assert_eq!(Uint256::MAX.carrying_add(Uint256::ZERO, true), (Uint256::ZERO, true));
+    assert_eq!(Uint256::MAX.carrying_add(Uint256::MAX, true), (Uint256::MAX, true));
+    assert_eq!(Uint256::MAX.carrying_add(Uint256::ZERO, false), (Uint256::MAX, false));
+    assert_eq!(Uint256::ZERO.borrowing_sub(Uint256::ZERO, true), (Uint256::MAX, true));
+    assert_eq!(Uint256::ZERO.borrowing_sub(Uint256::MAX, true), (Uint256::ZERO, true));
+    assert_eq!(Uint256::ONE.borrowing_sub(Uint256::ZERO, true), (Uint256::ZERO, false));
+}
+
+#[quickcheck]
+fn next_multiple_of_native(a: u64, b: u64, c: u128, d: u128) -> bool {
+    let small = Uint64::from_u64(a).checked_next_multiple_of(Uint64::from_u64(b))
+        == a.checked_next_multiple_of(b).map(Uint64::from_u64);
+    let wide = from_native_u128(c).checked_next_multiple_of(from_native_u128(d))
+        == c.checked_next_multiple_of(d).map(from_native_u128);
+    // The 256-bit result only matches where u128 does not overflow.
+    let wider = match c.checked_next_multiple_of(d) {
+        Some(m) => {
+            Uint256::from_u128(c).next_multiple_of(Uint256::from_u128(d)) == Uint256::from_u128(m)
+                && from_native_u128(c).next_multiple_of(from_native_u128(d)) == from_native_u128(m)
+        }
+        None => true,
+    };
+    small && wide && wider
+}
+
+#[quickcheck]
+fn uint256_next_multiple_of_bounds(l0: u64, l1: u64, l2: u64, l3: u64, d0: u64, d1: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let d = Uint256 { l0: d0, l1: d1, l2: 0, l3: 0 };
+    if d.is_zero() {
+        return a.checked_next_multiple_of(d).is_none();
+    }
+    match a.checked_next_multiple_of(d) {
+        Some(m) => (m % d).is_zero() && m >= a && m - a < d && m == a.next_multiple_of(d),
+        None => Uint256::MAX - Uint256::MAX % d < a,
+    }
+}
+
+#[test]
+fn next_multiple_of_edges() {
+    let d = Uint256::from_u128(10);
+    assert_eq!(Uint256::ZERO.next_multiple_of(d), Uint256::ZERO);
+    assert_eq!(Uint256::from_u128(11).next_multiple_of(d), Uint256::from_u128(20));
+    assert_eq!(Uint256::MAX.next_multiple_of(Uint256::ONE), Uint256::MAX);
+    assert_eq!(Uint256::MAX.checked_next_multiple_of(Uint256::from_u128(2)), None);
+    assert_eq!(Uint256::MAX.checked_next_multiple_of(Uint256::MAX), Some(Uint256::MAX));
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn uint256_next_multiple_of_zero() {
+    let _ = Uint256::ONE.next_multiple_of(Uint256::ZERO);
+}
+
+#[quickcheck]
+fn uint256_mul_u64_ethnum(l0: u64, l1: u64, l2: u64, l3: u64, d: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let (lo, carry) = a.widening_mul_u64(d);
+    let wide = Uint512::from(a).checked_mul(Uint512::from(d)).unwrap();
+    a.mul_u64(d) == lo
+        && to_ethnum(&lo) == to_ethnum(&a).wrapping_mul(ethnum::U256::from(d))
+        && wide == Uint512::from_halves(Uint256::from(carry), lo)
+}
+
+#[test]
+fn uint256_mul_u64_edges() {
+    // (2^256 - 1)(2^64 - 1) = (2^64 - 2) * 2^256 + (2^256 - 2^64 + 1)
+    let lo = Uint256::from(u64::MAX).wrapping_neg();
+    assert_eq!(Uint256::MAX.widening_mul_u64(u64::MAX), (lo, u64::MAX - 1));
+    assert_eq!(Uint256::MAX.widening_mul_u64(0), (Uint256::ZERO, 0));
+    assert_eq!(Uint256::MAX.widening_mul_u64(1), (Uint256::MAX, 0));
+    assert_eq!(Uint256::MAX.mul_u64(2), Uint256::MAX - Uint256::ONE);
+}
+
+#[quickcheck]
+fn uint256_to_f64_native(a: u128, shift: u8) -> bool {
+    let shift = (shift % 128) as u32;
+    let scale = 2f64.powi(shift as i32);
+    Uint256::from_u128(a).to_f64() == a as f64
+        && (Uint256::from_u128(a) << shift).to_f64() == a as f64 * scale
+}
+
+#[quickcheck]
+fn uint256_from_f64_truncates(v: f64) -> bool {
+    let expected = if v.is_nan() || v < 0.0 || v >= 2f64.powi(256) {
+        None
+    } else {
+        Some(v.trunc())
+    };
+    let fits_u128 = !v.is_nan() && v >= 0.0 && v < 2f64.powi(128);
+    Uint256::from_f64(v).map(Uint256::to_f64) == expected
+        && (!fits_u128 || Uint256::from_f64(v) == Some(Uint256::from_u128(v as u128)))
+}
+
+#[test]
+fn uint256_f64_conversions() {
+    for i in 0..1000u64 {
+        assert_eq!(Uint256::from_f64(i as f64), Some(Uint256::from(i)));
+        assert_eq!(Uint256::from(i).to_f64(), i as f64);
+    }
+    assert_eq!(Uint256::from_f64(2f64.powi(200)), Some(Uint256::ONE << 200u32));
+    assert_eq!((Uint256::ONE << 200u32).to_f64(), 2f64.powi(200));
+    assert_eq!(Uint256::from_f64(2f64.powi(256)), None);
+    assert_eq!(Uint256::from_f64(f64::INFINITY), None);
+    assert_eq!(Uint256::from_f64(f64::NAN), None);
+    assert_eq!(Uint256::from_f64(-1.0), None);
+    assert_eq!(Uint256::from_f64(-0.0), Some(Uint256::ZERO));
+    assert_eq!(Uint256::from_f64(0.999), Some(Uint256::ZERO));
+    assert_eq!(Uint256::from_f64(f64::MIN_POSITIVE), Some(Uint256::ZERO));
+    assert_eq!(Uint256::MAX.to_f64(), 2f64.powi(256));
+    // Exactly halfway between two f64 values rounds to the even significand.
+    let tie = (Uint256::ONE << 200u32) + (Uint256::ONE << 147u32);
+    assert_eq!(tie.to_f64(), 2f64.powi(200));
+    assert_eq!((tie + Uint256::ONE).to_f64(), 2f64.powi(200) + 2f64.powi(148));
+}
+
+/// `Int256::mul` must agree with `optimal_u256_mul` on the low 256 bits,
+/// whichever path the runtime dispatch picks on this host.
+#[cfg(target_arch = "x86_64")]
+#[quickcheck]
+fn int256_mul_matches_optimal_u256_mul(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let x = Int256::new(a.0, a.1, a.2, a.3);
+    let y = Int256::new(b.0, b.1, b.2, b.3);
+    (x * y).to_uint256() == crate::optimal_u256_mul(&x.to_uint256(), &y.to_uint256())
+}
+
+#[cfg(target_arch = "x86_64")]
+#[quickcheck]
+fn optimal_u256_mul_ethnum(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let x = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let y = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    to_ethnum(&crate::optimal_u256_mul(&x, &y)) == to_ethnum(&x).wrapping_mul(to_ethnum(&y))
+}
+
+/// Both sides of the BMI2 dispatch agree with each other and with
+/// `Uint256::mul`; the asm side is only exercised on hosts that have BMI2.
+#[cfg(target_arch = "x86_64")]
+#[quickcheck]
+fn optimal_u256_mul_dispatch_paths(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let x = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let y = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let asm_ok = !std::arch::is_x86_feature_detected!("bmi2")
+        || unsafe { crate::u256::optimal_u256_mul_bmi2(&x, &y) } == x * y;
+    asm_ok && crate::optimal_u256_mul(&x, &y) == x * y
+}
+
+#[test]
+fn uint256_const_wrapping_arithmetic() {
+    // 10^77 is the largest power of ten below 2^256.
+    const TEN_77: Uint256 = {
+        let ten = Uint256::from_u128(10);
+        let mut acc = Uint256::ONE;
+        let mut i = 0;
+        while i < 77 {
+            acc = acc.wrapping_mul(ten);
+            i += 1;
+        }
+        acc
+    };
+    const WRAPPED: Uint256 = Uint256::MAX.wrapping_add(Uint256::from_u128(2));
+    const BORROWED: Uint256 = Uint256::ZERO.wrapping_sub(Uint256::ONE);
+
+    assert_eq!(TEN_77.to_string(), format!("1{}", "0".repeat(77)));
+    assert_eq!(WRAPPED, Uint256::ONE);
+    assert_eq!(BORROWED, Uint256::MAX);
+}
+
+#[quickcheck]
+fn uint256_wrapping_arith_ethnum(l0: u64, l1: u64, l2: u64, l3: u64, m0: u64, m1: u64, m2: u64, m3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let b = Uint256 { l0: m0, l1: m1, l2: m2, l3: m3 };
+    let (x, y) = (to_ethnum(&a), to_ethnum(&b));
+    to_ethnum(&a.wrapping_add(b)) == x.wrapping_add(y)
+        && to_ethnum(&a.wrapping_sub(b)) == x.wrapping_sub(y)
+        && to_ethnum(&a.wrapping_mul(b)) == x.wrapping_mul(y)
+}
+
+#[quickcheck]
+fn uint256_radix_le_roundtrip(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    [2, 10, 16, 36, 58, 256].iter().all(|&radix| {
+        let digits = a.to_radix_le(radix);
+        digits.iter().all(|&d| (d as u32) < radix)
+            && (digits.len() == 1 || *digits.last().unwrap() != 0)
+            && Uint256::from_radix_le(&digits, radix) == Some(a)
+    })
+}
+
+#[quickcheck]
+fn uint256_radix_le_matches_formatting(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let render = |radix: u32| -> String {
+        a.to_radix_le(radix)
+            .iter()
+            .rev()
+            .map(|&d| char::from_digit(d as u32, radix).unwrap())
+            .collect()
+    };
+    let bytes = a.to_radix_le(256);
+    let mut le = to_ethnum(&a).to_le_bytes().to_vec();
+    while le.len() > 1 && le.last() == Some(&0) {
+        le.pop();
+    }
+    render(10) == a.to_string() && render(16) == format!("{a:x}") && bytes == le
+}
+
+#[test]
+fn uint256_radix_le_edges() {
+    assert_eq!(Uint256::ZERO.to_radix_le(58), vec![0]);
+    assert_eq!(Uint256::from_radix_le(&[], 10), Some(Uint256::ZERO));
+    assert_eq!(Uint256::from_radix_le(&[1, 2, 3], 10), Some(Uint256::from_u128(321)));
+    assert_eq!(Uint256::from_radix_le(&[10], 10), None);
+    assert_eq!(Uint256::from_radix_le(&[57, 0, 0], 58), Some(Uint256::from_u128(57)));
+
+    // MAX in radix 58, then one more in the top digit or an extra digit overflows.
+    let mut digits = Uint256::MAX.to_radix_le(58);
+    assert_eq!(Uint256::from_radix_le(&digits, 58), Some(Uint256::MAX));
+    digits[0] += 1;
+    assert_eq!(Uint256::from_radix_le(&digits, 58), None);
+    let mut wide = vec![0u8; 32];
+    wide.push(1);
+    assert_eq!(Uint256::from_radix_le(&wide, 256), None);
+    assert_eq!(Uint256::from_radix_le(&[0xff; 32], 256), Some(Uint256::MAX));
+}
+
+#[test]
+#[should_panic(expected = "radix must lie in the range `[2, 256]`")]
+fn uint256_to_radix_le_bad_radix() {
+    let _ = Uint256::ONE.to_radix_le(1);
+}
+
+#[quickcheck]
+fn uint256_iter_ones_rebuilds(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let ones: Vec<u32> = a.iter_ones().collect();
+    let mut rebuilt = Uint256::ZERO;
+    for &i in &ones {
+        rebuilt.set_bit(i);
+    }
+    let bits: Vec<bool> = a.iter_bits().collect();
+    rebuilt == a
+        && ones.windows(2).all(|w| w[0] < w[1])
+        && ones.len() as u32 == a.count_ones()
+        && bits.len() == 256
+        && bits.iter().enumerate().all(|(i, &b)| b == ones.contains(&(i as u32)))
+}
+
+#[test]
+fn uint256_iter_ones_known_bits() {
+    let mut a = Uint256::ZERO;
+    for i in [0, 63, 64, 127, 128, 200, 255] {
+        a.set_bit(i);
+    }
+    assert_eq!(a.iter_ones().collect::<Vec<_>>(), vec![0, 63, 64, 127, 128, 200, 255]);
+    assert_eq!(Uint256::ZERO.iter_ones().next(), None);
+    assert_eq!(Uint256::MAX.iter_ones().count(), 256);
+    assert_eq!((Uint256::ONE << 255u32).iter_ones().collect::<Vec<_>>(), vec![255]);
+    assert_eq!(Uint256::from_u128(5).iter_bits().take(4).collect::<Vec<_>>(), vec![true, false, true, false]);
+}
+
+#[quickcheck]
+fn mixed_width_unsigned_cmp(l0: u64, l1: u64, l2: u64, l3: u64, x: u64, y: u128) -> bool {
+    let wide = Uint256 { l0, l1, l2, l3 };
+    // Exercise both the narrowable and the high-limbs-set paths.
+    let small = Uint256 { l0, l1, l2: 0, l3: 0 };
+    let (a, b) = (Uint64::from_u64(x), from_native_u128(y));
+    [wide, small].iter().all(|&w| {
+        w.partial_cmp(&a) == w.partial_cmp(&Uint256::from(a))
+            && w.partial_cmp(&b) == w.partial_cmp(&Uint256::from(b))
+            && a.partial_cmp(&w) == Uint256::from(a).partial_cmp(&w)
+            && b.partial_cmp(&w) == Uint256::from(b).partial_cmp(&w)
+            && (w == a) == (w == Uint256::from(a))
+            && (b == w) == (Uint256::from(b) == w)
+    }) && b.partial_cmp(&a) == y.partial_cmp(&(x as u128))
+        && (a == b) == (x as u128 == y)
+}
+
+#[test]
+fn mixed_width_unsigned_cmp_edges() {
+    let threshold = Uint64::from_u64(1000);
+    assert!(Uint256::from_u128(1001) > threshold);
+    assert!(Uint256::from_u128(999) < threshold);
+    assert!(Uint256::from_u128(1000) == threshold);
+    // High limbs set force greater-than even when the low limb is smaller.
+    let big = Uint256 { l0: 0, l1: 0, l2: 0, l3: 1 };
+    assert!(big > Uint64::MAX);
+    assert!(big > from_native_u128(u128::MAX));
+    assert!(Uint64::MAX < big);
+    assert!(big != Uint64::ZERO);
+    assert!(from_native_u128(1 << 64) > Uint64::MAX);
+    assert!(Uint64::MAX == from_native_u128(u64::MAX as u128));
+}
+
+// Values scaled by 2^128 overflow 256 bits exactly when the unscaled
+// values overflow 128, so the native i128/u128 results carry over.
+#[quickcheck]
+fn mixed_sign_checked_add_native(a: u128, b: i128, c: i128, d: u128) -> bool {
+    let hi_u = |v: u128| Uint256::from_u128(v) << 128u32;
+    let hi_i = |v: i128| Int256::from_i128(v) << 128u32;
+    hi_u(a).checked_add_signed(hi_i(b)) == a.checked_add_signed(b).map(hi_u)
+        && hi_i(c).checked_add_unsigned(hi_u(d)) == c.checked_add_unsigned(d).map(hi_i)
+        && hi_i(c).checked_sub_unsigned(hi_u(d)) == c.checked_sub_unsigned(d).map(hi_i)
+}
+
+#[quickcheck]
+fn mixed_sign_checked_add_small(a: u64, b: i64, c: i64, d: u64) -> bool {
+    let (a, b, c, d) = (a as u128, b as i128, c as i128, d as u128);
+    Uint256::from_u128(a).checked_add_signed(Int256::from_i128(b))
+        == a.checked_add_signed(b).map(Uint256::from_u128)
+        && Int256::from_i128(c).checked_add_unsigned(Uint256::from_u128(d))
+            == c.checked_add_unsigned(d).map(Int256::from_i128)
+        && Int256::from_i128(c).checked_sub_unsigned(Uint256::from_u128(d))
+            == c.checked_sub_unsigned(d).map(Int256::from_i128)
+}
+
+#[test]
+fn mixed_sign_checked_add_edges() {
+    assert_eq!(Uint256::MAX.checked_add_signed(Int256::NEG_ONE), Some(Uint256::MAX - Uint256::ONE));
+    assert_eq!(Uint256::MAX.checked_add_signed(Int256::ONE), None);
+    assert_eq!(Uint256::ZERO.checked_add_signed(Int256::NEG_ONE), None);
+    assert_eq!(Uint256::ZERO.checked_add_signed(Int256::MAX), Some(Int256::MAX.to_uint256()));
+    assert_eq!(Int256::MIN.checked_add_unsigned(Uint256::MAX), Some(Int256::MAX));
+    assert_eq!(Int256::NEG_ONE.checked_add_unsigned(Uint256::MAX), None);
+    assert_eq!(Int256::MAX.checked_sub_unsigned(Uint256::MAX), Some(Int256::MIN));
+    assert_eq!(Int256::ZERO.checked_sub_unsigned(Uint256::MAX), None);
+}
+
+#[quickcheck]
+fn uint256_divrem_by_u64_identity(l0: u64, l1: u64, l2: u64, l3: u64, d: u64) -> bool {
+    if d == 0 {
+        return true;
+    }
+    let a = Uint256 { l0, l1, l2, l3 };
+    let (q, r) = a.divrem_by_u64(d);
+    r < d && q.widening_mul_u64(d) == (a - Uint256::from(r), 0) && q == a.div_by_u64(d)
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn uint256_divrem_by_u64_zero() {
+    let _ = Uint256::MAX.divrem_by_u64(0);
+}
+
+#[quickcheck]
+fn uint256_exact_div_products(q: (u64, u64), d: (u64, u64, u64), shift: u8) -> bool {
+    let d = Uint256 { l0: d.0 | 1, l1: d.1, l2: d.2, l3: 0 } << (shift % 64) as u32;
+    let q = Uint256::from_u128((q.1 as u128) << 64 | q.0 as u128);
+    // Keep the product within 256 bits so it is a true multiple.
+    match q.checked_mul(d) {
+        Some(n) if q.is_zero() => n.exact_div(d).is_zero(),
+        Some(n) => n.exact_div(d) == q && n.exact_div(q) == d,
+        None => true,
+    }
+}
+
+#[test]
+fn uint256_exact_div_known() {
+    let ten_pow = |n: u32| (0..n).fold(Uint256::ONE, |acc, _| acc.mul_u64(10));
+    assert_eq!(ten_pow(77).exact_div(ten_pow(38)), ten_pow(39));
+    assert_eq!(Uint256::MAX.exact_div(Uint256::MAX), Uint256::ONE);
+    assert_eq!(Uint256::MAX.exact_div(Uint256::ONE), Uint256::MAX);
+    // 2^256 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * ...
+    assert_eq!(Uint256::MAX.exact_div(Uint256::from_u128(3 * 5 * 17)), Uint256::MAX / Uint256::from_u128(255));
+    assert_eq!((Uint256::ONE << 255u32).exact_div(Uint256::ONE << 200u32), Uint256::ONE << 55u32);
+    assert_eq!(Uint256::ZERO.exact_div(Uint256::from_u128(12)), Uint256::ZERO);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "exact_div: divisor does not divide the dividend")]
+fn uint256_exact_div_inexact() {
+    let _ = Uint256::from_u128(7).exact_div(Uint256::from_u128(2));
+}
+
+#[quickcheck]
+fn montgomery256_mul_matches_mulmod(
+    a: (u64, u64, u64, u64),
+    b: (u64, u64, u64, u64),
+    m: (u64, u64, u64, u64),
+) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    // Odd moduli of full and reduced width
+    let full = Uint256 { l0: m.0 | 1, l1: m.1, l2: m.2, l3: m.3 };
+    let small = Uint256::from_u128((m.1 as u128) << 64 | (m.0 | 1) as u128);
+    [full, small, Uint256::MAX, Uint256::ONE].iter().all(|&m| {
+        let ctx = Montgomery256::new(m).unwrap();
+        let (x, y) = (ctx.to_mont(a), ctx.to_mont(b));
+        x < m && ctx.from_mont(x) == a % m && ctx.from_mont(ctx.mul(x, y)) == a.mulmod(b, m)
+    })
+}
+
+#[quickcheck]
+fn montgomery256_pow_chain(a: u64, e: u8, m: (u64, u64, u64, u64)) -> bool {
+    let m = Uint256 { l0: m.0 | 1, l1: m.1, l2: m.2, l3: m.3 };
+    let ctx = Montgomery256::new(m).unwrap();
+    let base = Uint256::from(a);
+    let mut mont = ctx.to_mont(Uint256::ONE);
+    let mut plain = Uint256::ONE % m;
+    for _ in 0..e {
+        mont = ctx.mul(mont, ctx.to_mont(base));
+        plain = plain.mulmod(base, m);
+    }
+    ctx.from_mont(mont) == plain
+}
+
+#[test]
+fn montgomery256_rejects_even_modulus() {
+    assert!(Montgomery256::new(Uint256::ZERO).is_none());
+    assert!(Montgomery256::new(Uint256::from_u128(10)).is_none());
+    assert!(Montgomery256::new(Uint256::MAX - Uint256::ONE).is_none());
+    let ctx = Montgomery256::new(Uint256::from_u128(97)).unwrap();
+    assert_eq!(ctx.modulus(), Uint256::from_u128(97));
+    let six = ctx.mul(ctx.to_mont(Uint256::from_u128(2)), ctx.to_mont(Uint256::from_u128(3)));
+    assert_eq!(ctx.from_mont(six), Uint256::from_u128(6));
+    assert_eq!(ctx.from_mont(ctx.to_mont(Uint256::from_u128(100))), Uint256::from_u128(3));
+}
+
+#[quickcheck]
+fn barrett256_matches_mulmod(
+    a: (u64, u64, u64, u64),
+    b: (u64, u64, u64, u64),
+    m: (u64, u64, u64, u64),
+) -> bool {
+    let a = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let b = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let full = Uint256 { l0: m.0, l1: m.1, l2: m.2, l3: m.3 };
+    let small = Uint256::from(m.0);
+    [full, small, full >> 128u32, Uint256::MAX]
+        .iter()
+        .filter(|m| !m.is_zero())
+        .all(|&m| {
+            let ctx = Barrett256::new(m).unwrap();
+            ctx.reduce(a.widening_mul(b)) == a.mulmod(b, m)
+                && ctx.reduce((Uint256::ZERO, a)) == a % m
+                && ctx.mulmod(a, b) == a.mulmod(b, m)
+        })
+}
+
+#[test]
+fn barrett256_even_and_tiny_moduli() {
+    assert!(Barrett256::new(Uint256::ZERO).is_none());
+    let max = (Uint256::MAX, Uint256::MAX);
+    for m in [1u128, 2, 3, 10, 1 << 64, (1 << 127) + 2, u128::MAX - 1] {
+        let m = Uint256::from_u128(m);
+        let ctx = Barrett256::new(m).unwrap();
+        assert_eq!(ctx.modulus(), m);
+        let expected = (Uint512::MAX % Uint512::from(m)).split().1;
+        assert_eq!(ctx.reduce(max), expected);
+        assert_eq!(ctx.mulmod(Uint256::MAX, Uint256::MAX), Uint256::MAX.mulmod(Uint256::MAX, m));
+    }
+    let pow2 = Barrett256::new(Uint256::ONE << 255u32).unwrap();
+    assert_eq!(pow2.reduce(max), Uint256::MAX >> 1u32);
+    let top = Barrett256::new(Uint256::MAX).unwrap();
+    assert_eq!(top.reduce((Uint256::ONE, Uint256::ZERO)), Uint256::ONE);
+}
+
+#[quickcheck]
+fn wrapping_div_rem_signed_native(a: i64, b: i64, c: i128, d: i128) -> bool {
+    let small = b == 0 || {
+        let (x, y) = (Int64::from_i64(a), Int64::from_i64(b));
+        x.wrapping_div(y) == Int64::from_i64(a.wrapping_div(b))
+            && x.wrapping_rem(y) == Int64::from_i64(a.wrapping_rem(b))
+    };
+    let wide = d == 0 || {
+        let (x, y) = (Int128::from_i128(c), Int128::from_i128(d));
+        let (u, v) = (Int256::from_i128(c), Int256::from_i128(d));
+        x.wrapping_div(y) == Int128::from_i128(c.wrapping_div(d))
+            && x.wrapping_rem(y) == Int128::from_i128(c.wrapping_rem(d))
+            // i128::MIN / -1 is in range at 256 bits.
+            && u.wrapping_div(v) == u / v
+            && u.wrapping_rem(v) == u % v
+    };
+    small && wide
+}
+
+#[quickcheck]
+fn wrapping_div_rem_unsigned_native(a: u64, b: u64, c: u128, d: u128) -> bool {
+    let small = b == 0 || {
+        let (x, y) = (Uint64::from_u64(a), Uint64::from_u64(b));
+        x.wrapping_div(y) == Uint64::from_u64(a / b) && x.wrapping_rem(y) == Uint64::from_u64(a % b)
+    };
+    let wide = d == 0 || {
+        let (x, y) = (from_native_u128(c), from_native_u128(d));
+        let (u, v) = (Uint256::from_u128(c), Uint256::from_u128(d));
+        x.wrapping_div(y) == from_native_u128(c / d)
+            && x.wrapping_rem(y) == from_native_u128(c % d)
+            && u.wrapping_div(v) == Uint256::from_u128(c / d)
+            && u.wrapping_rem(v) == Uint256::from_u128(c % d)
+    };
+    small && wide
+}
+
+#[test]
+fn wrapping_div_rem_min_by_neg_one() {
+    assert_eq!(Int256::MIN.wrapping_div(Int256::NEG_ONE), Int256::MIN);
+    assert_eq!(Int256::MIN.wrapping_rem(Int256::NEG_ONE), Int256::ZERO);
+    assert_eq!(Int128::MIN.wrapping_div(Int128::NEG_ONE), Int128::MIN);
+    assert_eq!(Int128::MIN.wrapping_rem(Int128::NEG_ONE), Int128::ZERO);
+    assert_eq!(Int64::MIN.wrapping_div(Int64::NEG_ONE), Int64::from_i64(i64::MIN.wrapping_div(-1)));
+    assert_eq!(Int64::MIN.wrapping_rem(Int64::NEG_ONE), Int64::from_i64(i64::MIN.wrapping_rem(-1)));
+    assert_eq!(Int256::MIN.wrapping_div(Int256::ONE), Int256::MIN);
+}
+
+#[quickcheck]
+fn uint128_swap_bytes_reverse_bits_native(a: u128) -> bool {
+    let x = from_native_u128(a);
+    x.swap_bytes() == from_native_u128(a.swap_bytes())
+        && x.reverse_bits() == from_native_u128(a.reverse_bits())
+}
+
+#[quickcheck]
+fn uint256_swap_bytes_reverse_bits(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let a = Uint256 { l0, l1, l2, l3 };
+    let mut be = to_ethnum(&a).to_be_bytes();
+    be.reverse();
+    to_ethnum(&a.swap_bytes()) == ethnum::U256::from_be_bytes(be)
+        && a.swap_bytes().swap_bytes() == a
+        && a.reverse_bits().reverse_bits() == a
+        && (0..256u32).all(|i| a.reverse_bits().bit(i) == a.bit(255 - i))
+}
+
+#[test]
+fn uint256_swap_bytes_reverse_bits_vector() {
+    let a = Uint256 {
+        l0: 0x0001_0203_0405_0607,
+        l1: 0x0809_0a0b_0c0d_0e0f,
+        l2: 0x1011_1213_1415_1617,
+        l3: 0x1819_1a1b_1c1d_1e1f,
+    };
+    let swapped = Uint256 {
+        l0: 0x1f1e_1d1c_1b1a_1918,
+        l1: 0x1716_1514_1312_1110,
+        l2: 0x0f0e_0d0c_0b0a_0908,
+        l3: 0x0706_0504_0302_0100,
+    };
+    assert_eq!(a.swap_bytes(), swapped);
+    assert_eq!(Uint256::ONE.reverse_bits(), Uint256::ONE << 255u32);
+    assert_eq!((Uint256::ONE << 64u32).reverse_bits(), Uint256::ONE << 191u32);
+    assert_eq!(Uint256::from_u128(0xff).swap_bytes(), Uint256::from(0xffu8) << 248u32);
+}
+
+#[quickcheck]
+fn uint256_int256_byte_roundtrip(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let u = Uint256 { l0, l1, l2, l3 };
+    let i = Int256::new(l0, l1, l2, l3);
+    let e = to_ethnum(&u);
+    u.to_le_bytes() == e.to_le_bytes()
+        && u.to_be_bytes() == e.to_be_bytes()
+        && u.to_ne_bytes() == e.to_ne_bytes()
+        && Uint256::from_le_bytes(u.to_le_bytes()) == u
+        && Uint256::from_be_bytes(u.to_be_bytes()) == u
+        && Uint256::from_ne_bytes(u.to_ne_bytes()) == u
+        && i.to_le_bytes() == u.to_le_bytes()
+        && i.to_be_bytes() == u.to_be_bytes()
+        && Int256::from_le_bytes(i.to_le_bytes()) == i
+        && Int256::from_be_bytes(i.to_be_bytes()) == i
+        && Int256::from_ne_bytes(i.to_ne_bytes()) == i
+}
+
+/// The native-endian bytes are the struct's memory, whatever the target's
+/// byte order.
+#[quickcheck]
+fn ne_bytes_match_memory(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let u = Uint256 { l0, l1, l2, l3 };
+    let i = Int256::new(l0, l1, l2, l3);
+    let u128_ = Uint128 { l: l0, h: l1 };
+    let i128_ = Int128::new(l2, l3);
+    // SAFETY: all four types are repr(C) structs of u64 limbs with no padding.
+    unsafe {
+        u.to_ne_bytes() == std::mem::transmute::<Uint256, [u8; 32]>(u)
+            && i.to_ne_bytes() == std::mem::transmute::<Int256, [u8; 32]>(i)
+            && u128_.to_ne_bytes() == std::mem::transmute::<Uint128, [u8; 16]>(u128_)
+            && i128_.to_ne_bytes() == std::mem::transmute::<Int128, [u8; 16]>(i128_)
+    }
+}
+
+#[quickcheck]
+fn ne_bytes_128_native(a: u128, b: i128) -> bool {
+    from_native_u128(a).to_ne_bytes() == a.to_ne_bytes()
+        && Uint128::from_ne_bytes(a.to_ne_bytes()) == from_native_u128(a)
+        && Int128::from_i128(b).to_ne_bytes() == b.to_ne_bytes()
+        && Int128::from_ne_bytes(b.to_ne_bytes()) == Int128::from_i128(b)
+}
+
+#[quickcheck]
+fn uint256_limbs_roundtrip(a: u64, b: u64, c: u64, d: u64) -> bool {
+    let x = Uint256::from_limbs([a, b, c, d]);
+    x.to_limbs() == [a, b, c, d]
+        && to_ethnum(&x)
+            == ethnum::U256::from_words((d as u128) << 64 | c as u128, (b as u128) << 64 | a as u128)
+}
+
+#[test]
+fn uint256_try_from_be_slice() {
+    assert_eq!(Uint256::try_from(&[][..]), Ok(Uint256::ZERO));
+    assert_eq!(Uint256::try_from(&[0xab][..]), Ok(Uint256::from(0xabu64)));
+    assert_eq!(Uint256::try_from(&[0xff; 32][..]), Ok(Uint256::MAX));
+
+    let mut long = [0u8; 40];
+    long[39] = 1;
+    assert_eq!(Uint256::try_from(&long[..]), Ok(Uint256::ONE));
+    long[7] = 1;
+    assert!(Uint256::try_from(&long[..]).is_err());
+    assert!(Uint256::try_from(&[0xff; 33][..]).is_err());
+}
+
+#[quickcheck]
+fn uint256_try_from_slice_matches_be_bytes(l0: u64, l1: u64, l2: u64, l3: u64, cut: u8) -> bool {
+    let x = Uint256 { l0, l1, l2, l3 };
+    let bytes = x.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&b| b == 0).count().min(cut as usize % 33);
+    Uint256::try_from(&bytes[skip..]) == Ok(x)
+}
+
+#[quickcheck]
+fn uint256_mul_add_u64(l0: u64, l1: u64, l2: u64, l3: u64, mul: u64, add: u64) -> bool {
+    let x = Uint256 { l0, l1, l2, l3 };
+    let e = to_ethnum(&x);
+    let (m, o1) = e.overflowing_mul(ethnum::U256::from(mul));
+    let (expected, o2) = m.overflowing_add(ethnum::U256::from(add));
+    let checked = (!o1 && !o2).then_some(expected);
+    to_ethnum(&x.mul_add_u64(mul, add)) == expected
+        && x.checked_mul_add_u64(mul, add).map(|r| to_ethnum(&r)) == checked
+        && x.checked_mul_u64(mul).map(|r| to_ethnum(&r)) == e.checked_mul(ethnum::U256::from(mul))
+}
+
+#[quickcheck]
+fn uint256_mul_add_u64_horner(digits: Vec<u8>) -> bool {
+    // Horner's method over base-10 digits agrees with parsing the string.
+    let digits: Vec<u8> = digits.iter().take(70).map(|d| d % 10).collect();
+    let s: String = digits.iter().map(|d| (b'0' + d) as char).collect();
+    let acc = digits.iter().fold(Uint256::ZERO, |acc, &d| acc.mul_add_u64(10, d as u64));
+    match ethnum::U256::from_str_radix(&s, 10) {
+        Ok(e) => to_ethnum(&acc) == e,
+        Err(_) => true,
+    }
+}
+
+#[test]
+fn uint256_mul_add_u64_edges() {
+    // (2^256 - 1)(2^64 - 1) + (2^64 - 1) = (2^64 - 1) * 2^256
+    assert_eq!(
+        Uint256::MAX.widening_mul_add_u64(u64::MAX, u64::MAX),
+        (Uint256::ZERO, u64::MAX)
+    );
+    assert_eq!(Uint256::MAX.checked_mul_add_u64(1, 0), Some(Uint256::MAX));
+    assert_eq!(Uint256::MAX.checked_mul_add_u64(1, 1), None);
+    assert_eq!(Uint256::MAX.checked_mul_u64(2), None);
+}
+
+#[quickcheck]
+fn int256_logical_shr(l0: u64, l1: u64, l2: u64, l3: u64, n: u16) -> bool {
+    let x = Int256::new(l0, l1, l2, l3);
+    let n = n as u32 % 300;
+    let logical = x.logical_shr(n);
+    let via_unsigned = Int256::from_uint256(x.to_uint256() >> n);
+    let expected = to_ethnum_i256(&x).as_u256().checked_shr(n).unwrap_or_default();
+    // On a negative value the top bit is set, so any nonzero shift differs.
+    let distinct = !x.is_negative() || n == 0 || logical != x >> n;
+    logical == via_unsigned && to_ethnum_i256(&logical) == expected.as_i256() && distinct
+}
+
+#[test]
+fn int256_logical_shr_negative() {
+    assert_eq!(Int256::NEG_ONE >> 255u32, Int256::NEG_ONE);
+    assert_eq!(Int256::NEG_ONE.logical_shr(255), Int256::ONE);
+    assert_eq!(Int256::NEG_ONE.logical_shr(1), Int256::MAX);
+    assert_eq!(Int256::MIN.logical_shr(256), Int256::ZERO);
+}
+
+#[quickcheck]
+fn uint256_be_words(a: u64, b: u64, c: u64, d: u64) -> bool {
+    let x = Uint256::from_be_words([a, b, c, d]);
+    x.to_be_words() == [a, b, c, d] && x == Uint256::from_limbs([d, c, b, a])
+}
+
+#[test]
+fn uint256_be_words_one() {
+    assert_eq!(Uint256::from_be_words([0, 0, 0, 1]), Uint256::ONE);
+    assert_eq!(Uint256::ONE.to_be_words(), [0, 0, 0, 1]);
+    assert_eq!(Uint256::from_be_words([1, 0, 0, 0]), Uint256::ONE << 192u32);
+}
+
+#[quickcheck]
+fn min_max_clamp_match_native(a: i64, b: i64, c: i64) -> bool {
+    let (lo, hi) = if b <= c { (b, c) } else { (c, b) };
+    let u = |x: i64| x as u64;
+    let (x, y) = (Uint256::from(u(a)), Uint256::from(u(b)));
+    Int64::from_i64(a).min(Int64::from_i64(b)) == Int64::from_i64(a.min(b))
+        && Int64::from_i64(a).max(Int64::from_i64(b)) == Int64::from_i64(a.max(b))
+        && Int64::from_i64(a).clamp(Int64::from_i64(lo), Int64::from_i64(hi))
+            == Int64::from_i64(a.clamp(lo, hi))
+        && Uint64::from_u64(u(a)).min(Uint64::from_u64(u(b)))
+            == Uint64::from_u64(u(a).min(u(b)))
+        && Int128::from(a).clamp(Int128::from(lo), Int128::from(hi))
+            == Int128::from(a.clamp(lo, hi))
+        && from_native_u128(u(a) as u128).max(from_native_u128(u(b) as u128))
+            == from_native_u128(u(a).max(u(b)) as u128)
+        && x.min(y) == Uint256::from(u(a).min(u(b)))
+        && x.max(y) == Uint256::from(u(a).max(u(b)))
+        && Int256::from(a).clamp(Int256::from(lo), Int256::from(hi))
+            == Int256::from(a.clamp(lo, hi))
+}
+
+#[test]
+fn clamp_boundaries() {
+    let lo = Uint256::from(10u64);
+    let hi = Uint256::from(20u64);
+    assert_eq!(Uint256::ZERO.clamp(lo, hi), lo);
+    assert_eq!(lo.clamp(lo, hi), lo);
+    assert_eq!(Uint256::from(15u64).clamp(lo, hi), Uint256::from(15u64));
+    assert_eq!(hi.clamp(lo, hi), hi);
+    assert_eq!(Uint256::MAX.clamp(lo, hi), hi);
+    assert_eq!(Int256::MIN.clamp(Int256::NEG_ONE, Int256::ONE), Int256::NEG_ONE);
+    assert_eq!(Int256::MAX.clamp(Int256::NEG_ONE, Int256::ONE), Int256::ONE);
+    assert_eq!(Uint256::ONE.clamp(Uint256::ONE, Uint256::ONE), Uint256::ONE);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "clamp: lo must not exceed hi")]
+fn clamp_inverted_bounds_panics() {
+    Uint256::from(5u64).clamp(Uint256::from(20u64), Uint256::from(10u64));
+}
+
+#[quickcheck]
+fn usize_isize_roundtrip(a: usize, b: isize) -> bool {
+    usize::try_from(Uint256::from(a)) == Ok(a)
+        && to_ethnum(&Uint256::from(a)) == ethnum::U256::from(a as u128)
+        && isize::try_from(Int256::from(b)) == Ok(b)
+        && Int256::from(b) == Int256::from_i128(b as i128)
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn usize_isize_bounds() {
+    assert_eq!(usize::try_from(Uint256::from(u64::MAX)), Ok(usize::MAX));
+    assert!(usize::try_from(Uint256::from(u64::MAX) + Uint256::ONE).is_err());
+    assert!(usize::try_from(Uint256::MAX).is_err());
+
+    assert_eq!(isize::try_from(Int256::from(i64::MAX)), Ok(isize::MAX));
+    assert_eq!(isize::try_from(Int256::from(i64::MIN)), Ok(isize::MIN));
+    assert!(isize::try_from(Int256::from(i64::MAX) + Int256::ONE).is_err());
+    assert!(isize::try_from(Int256::from(i64::MIN) - Int256::ONE).is_err());
+    assert!(isize::try_from(Int256::MIN).is_err());
+}
+
+#[test]
+fn uint256_carrying_add_bit() {
+    assert_eq!(Uint256::MAX.carrying_add_bit(Uint256::ONE), (Uint256::ZERO, true));
+    assert_eq!(Uint256::MAX.carrying_add_bit(Uint256::ZERO), (Uint256::MAX, false));
+    assert_eq!(Uint256::MAX.carrying_add_bit(Uint256::MAX), (Uint256::MAX - Uint256::ONE, true));
+}
+
+#[quickcheck]
+fn uint256_carrying_add_bit_accumulates(xs: Vec<(u64, u64, u64, u64)>) -> bool {
+    // Sum into a (high, low) pair, counting carries into the high half.
+    let mut lo = Uint256::ZERO;
+    let mut hi = Uint256::ZERO;
+    let mut expected = Uint512::ZERO;
+    for &(l0, l1, l2, l3) in &xs {
+        let x = Uint256 { l0, l1, l2, l3 };
+        let (sum, carry) = lo.carrying_add_bit(x);
+        lo = sum;
+        hi += Uint256::from(carry as u64);
+        expected += Uint512::from(x);
+    }
+    Uint512::from_halves(hi, lo) == expected
+}
+
+#[quickcheck]
+fn uint256_rem_u64(l0: u64, l1: u64, l2: u64, l3: u64, d: u64) -> bool {
+    if d == 0 {
+        return true;
+    }
+    let a = Uint256 { l0, l1, l2, l3 };
+    let expected = to_ethnum(&a) % ethnum::U256::from(d);
+    ethnum::U256::from(a.rem_u64(d)) == expected && a.rem_u64(d) == a.divrem_by_u64(d).1
+}
+
+#[test]
+fn uint256_rem_u64_edges() {
+    assert_eq!(Uint256::MAX.rem_u64(1), 0);
+    assert_eq!(Uint256::MAX.rem_u64(u64::MAX), 0);
+    assert_eq!(Uint256::MAX.rem_u64(1 << 63), (1 << 63) - 1);
+    assert_eq!(Uint256::ZERO.rem_u64(7), 0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn uint256_rem_u64_by_zero() {
+    let _ = Uint256::ONE.rem_u64(0);
+}
+
+#[test]
+fn parity() {
+    assert!(Uint64::ZERO.is_even() && !Uint64::ZERO.is_odd());
+    assert!(Int64::ZERO.is_even() && Int64::NEG_ONE.is_odd());
+    assert!(Uint128::MAX.is_odd() && !Uint128::MAX.is_even());
+    assert!(Int128::MIN.is_even() && Int128::MAX.is_odd());
+    assert!(Uint256::ZERO.is_even() && Uint256::ONE.is_odd());
+    assert!((Uint256::ONE << 64u32).is_even() && Uint256::MAX.is_odd());
+    assert!(Int256::MIN.is_even() && Int256::NEG_ONE.is_odd());
+    assert!(Int256::from_i128(-2).is_even() && Int256::from_i128(-3).is_odd());
+}
+
+#[quickcheck]
+fn parity_matches_native(a: i128) -> bool {
+    let even = a % 2 == 0;
+    let u = a as u128;
+    Int128::from_i128(a).is_even() == even
+        && Int256::from_i128(a).is_odd() != even
+        && from_native_u128(u).is_even() == even
+        && Uint256::from_u128(u).is_odd() != even
+        && Int64::from_i64(a as i64).is_even() == (a as i64 % 2 == 0)
+        && Uint64::from_u64(u as u64).is_odd() == (u as u64 % 2 == 1)
+}
+
+#[test]
+fn uint256_from_slice() {
+    assert_eq!(Uint256::from_be_slice(&[]), Ok(Uint256::ZERO));
+    assert_eq!(Uint256::from_le_slice(&[]), Ok(Uint256::ZERO));
+    assert_eq!(Uint256::from_be_slice(&[1, 2, 3]), Ok(Uint256::from(0x010203u64)));
+    assert_eq!(Uint256::from_le_slice(&[1, 2, 3]), Ok(Uint256::from(0x030201u64)));
+    assert_eq!(Uint256::from_be_slice(&[0xff; 32]), Ok(Uint256::MAX));
+    assert_eq!(Uint256::from_be_slice(&[0; 33]), Err(TryFromSliceError(())));
+    assert_eq!(Uint256::from_le_slice(&[0; 33]), Err(TryFromSliceError(())));
+    assert_eq!(
+        TryFromSliceError(()).to_string(),
+        <[u8; 1]>::try_from(&[][..]).unwrap_err().to_string()
+    );
+}
+
+#[quickcheck]
+fn uint256_from_slice_prefix(l0: u64, l1: u64, l2: u64, l3: u64, len: u8) -> bool {
+    // Truncating to the low `len` bytes in either byte order gives the
+    // value modulo 2^(8 * len).
+    let x = Uint256 { l0, l1, l2, l3 };
+    let len = len as usize % 33;
+    let low = if len == 32 { x } else { x & ((Uint256::ONE << (8 * len as u32)) - Uint256::ONE) };
+    Uint256::from_le_slice(&x.to_le_bytes()[..len]) == Ok(low)
+        && Uint256::from_be_slice(&x.to_be_bytes()[32 - len..]) == Ok(low)
+}
+
+#[quickcheck]
+fn uint256_mul_div_pow2(l0: u64, l1: u64, l2: u64, l3: u64, k: u16) -> bool {
+    let x = Uint256 { l0, l1, l2, l3 };
+    let k = k as u32 % 300;
+    let pow = if k < 256 { Uint256::ONE << k } else { Uint256::ZERO };
+    let quotient = if k < 256 { x / pow } else { Uint256::ZERO };
+    x.mul_pow2(3) == x * Uint256::from(8u64)
+        && x.mul_pow2(k) == x.wrapping_mul(pow)
+        && x.div_pow2(k) == quotient
+}
+
+#[test]
+fn uint256_limbs_match_ark_ff_bn254_modulus() {
+    // The BN254 scalar field modulus as ark-ff's `BigInt<4>` stores it.
+    let limbs = [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029];
+    let r: Uint256 = "21888242871839275222246405745257275088548364400416034343698204186575808495617"
+        .parse()
+        .unwrap();
+    assert_eq!(Uint256::from_limbs(limbs), r);
+    assert_eq!(r.to_limbs(), limbs);
+}
+
+#[quickcheck]
+fn int256_conditional_negate(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let x = Int256::new(l0, l1, l2, l3);
+    x.conditional_negate(true) == -x && x.conditional_negate(false) == x
+}
+
+#[test]
+fn int256_conditional_negate_edges() {
+    for x in [Int256::MIN, Int256::MAX, Int256::ZERO, Int256::ONE, Int256::NEG_ONE] {
+        assert_eq!(x.conditional_negate(true), -x);
+        assert_eq!(x.conditional_negate(false), x);
+    }
+    assert_eq!(Int256::MIN.conditional_negate(true), Int256::MIN);
+    assert_eq!(Int256::ONE.conditional_negate(true), Int256::NEG_ONE);
+    // The carry runs through every limb when the low limbs are zero.
+    assert_eq!(
+        Int256::new(0, 0, 0, 1).conditional_negate(true),
+        Int256::new(0, 0, 0, u64::MAX)
+    );
+}
+
+#[quickcheck]
+fn leading_ones_native(a: u128, ones: u8) -> bool {
+    // Set the top `ones` bits so long runs come up, not just the odd 1 or 2.
+    let ones = ones as u32 % 129;
+    let a = a | !u128::MAX.checked_shr(ones).unwrap_or(0);
+    let wide = Uint256::from_u128(a) | !(Uint256::MAX >> 128u32);
+    from_native_u128(a).leading_ones() == a.leading_ones()
+        && Uint64::from_u64(a as u64).leading_ones() == (a as u64).leading_ones()
+        && wide.leading_ones() == 128 + a.leading_ones()
+        && to_ethnum(&wide).leading_ones() == wide.leading_ones()
+}
+
+#[test]
+fn leading_ones_edges() {
+    assert_eq!(Uint64::MAX.leading_ones(), 64);
+    assert_eq!(Uint128::MAX.leading_ones(), 128);
+    assert_eq!(Uint256::MAX.leading_ones(), 256);
+    assert_eq!(Uint256::ZERO.leading_ones(), 0);
+    assert_eq!((Uint256::MAX >> 1u32).leading_ones(), 0);
+    assert_eq!((Uint256::MAX << 65u32).leading_ones(), 191);
+    assert_eq!(Uint256::from_limbs([u64::MAX, 0, u64::MAX, u64::MAX]).leading_ones(), 128);
+}
+
+#[quickcheck]
+fn uint256_normalize(l0: u64, l1: u64, l2: u64, l3: u64) -> bool {
+    let x = Uint256 { l0, l1, l2, l3 } >> (l0 as u32 % 256);
+    let (n, shift) = x.normalize();
+    if x.is_zero() {
+        return (n, shift) == (Uint256::ZERO, 256);
+    }
+    n.bit(255) && shift == x.leading_zeros() && n >> shift == x
+}
+
+#[quickcheck]
+fn uint256_mulhi(a: (u64, u64, u64, u64), b: (u64, u64, u64, u64)) -> bool {
+    let x = Uint256 { l0: a.0, l1: a.1, l2: a.2, l3: a.3 };
+    let y = Uint256 { l0: b.0, l1: b.1, l2: b.2, l3: b.3 };
+    let (hi, lo) = x.widening_mul(y);
+    x.mulhi(y) == hi
+        && Uint512::from_halves(hi, lo) == Uint512::from(x) * Uint512::from(y)
+        && x.mulhi(y) == y.mulhi(x)
+}
+
+#[test]
+fn uint256_mulhi_edges() {
+    assert_eq!(Uint256::MAX.mulhi(Uint256::MAX), Uint256::MAX - Uint256::ONE);
+    assert_eq!(Uint256::MAX.mulhi(Uint256::ONE), Uint256::ZERO);
+    assert_eq!((Uint256::ONE << 255u32).mulhi(Uint256::from(4u64)), Uint256::from(2u64));
+}
+
+#[quickcheck]
+fn uint256_shl_lossless(l0: u64, l1: u64, l2: u64, l3: u64, n: u16) -> bool {
+    let x = Uint256 { l0, l1, l2, l3 } >> (l1 as u32 % 256);
+    let n = n as u32 % 300;
+    let (shifted, lost) = x.shl_lossless(n);
+    // Nothing was lost exactly when shifting back recovers the value.
+    let kept = x.is_zero() || (n < 256 && shifted >> n == x);
+    shifted == x << n && lost != kept
+}
+
+#[test]
+fn uint256_shl_lossless_edges() {
+    let top = Uint256::ONE << 255u32;
+    assert_eq!(top.shl_lossless(0), (top, false));
+    assert_eq!(top.shl_lossless(1), (Uint256::ZERO, true));
+    assert_eq!(Uint256::ONE.shl_lossless(255), (top, false));
+    assert_eq!(Uint256::ONE.shl_lossless(256), (Uint256::ZERO, true));
+    assert_eq!(Uint256::ZERO.shl_lossless(1000), (Uint256::ZERO, false));
+    assert_eq!(Uint256::MAX.shl_lossless(1), (Uint256::MAX - Uint256::ONE, true));
+}
+
+#[quickcheck]
+fn uint256_shl_add(l0: u64, l1: u64, l2: u64, l3: u64, shift: u16, add: u64) -> bool {
+    let x = Uint256 { l0, l1, l2, l3 };
+    let shift = shift as u32 % 300;
+    x.shl_add(shift, add) == (x << shift).wrapping_add(Uint256::from(add))
+}
+
+#[test]
+fn uint256_shl_add_edges() {
+    // The addend carries through every limb.
+    let low_ones = Uint256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0]);
+    assert_eq!(low_ones.shl_add(0, 1), Uint256::ONE << 192u32);
+    assert_eq!(Uint256::MAX.shl_add(0, 1), Uint256::ZERO);
+    assert_eq!(Uint256::MAX.shl_add(256, 7), Uint256::from(7u64));
+
+    // Hex digits accumulated four bits at a time.
+    let hex = "deadbeef0123456789abcdef";
+    let acc = hex
+        .chars()
+        .fold(Uint256::ZERO, |acc, c| acc.shl_add(4, c.to_digit(16).unwrap() as u64));
+    assert_eq!(acc, Uint256::from_str_radix(hex, 16).unwrap());
+}
+
+#[quickcheck]
+fn uint64_divrem_by_u64(n: u64, d: u64, small: bool) -> bool {
+    // Half the divisors fit in 32 bits to cover the limb-walking path.
+    let d = if small { d >> 32 } else { d };
+    if d == 0 {
+        return true;
+    }
+    Uint64::from_u64(n).divrem_by_u64(d) == (Uint64::from_u64(n / d), n % d)
+}
+
+#[test]
+fn uint64_divrem_by_u64_edges() {
+    assert_eq!(Uint64::MAX.divrem_by_u64(1), (Uint64::MAX, 0));
+    let m = u32::MAX as u64;
+    assert_eq!(Uint64::MAX.divrem_by_u64(m), (Uint64::from_u64(m + 2), 0));
+    assert_eq!(Uint64::MAX.divrem_by_u64(1 << 32), (Uint64::from_u64(m), m));
+    assert_eq!(Uint64::MAX.divrem_by_u64(u64::MAX), (Uint64::from_u64(1), 0));
+    assert_eq!(Uint64::ZERO.divrem_by_u64(3), (Uint64::ZERO, 0));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn uint64_divrem_by_u64_zero() {
+    let _ = Uint64::ONE.divrem_by_u64(0);
+}
+
+#[test]
+fn int256_from_str_prefixed() {
+    let parse = Int256::from_str_prefixed;
+    let int = Int256::from_i128;
+    assert_eq!(parse("0xFF"), Ok(int(255)));
+    assert_eq!(parse("-0xff"), Ok(int(-255)));
+    assert_eq!(parse("+0XfF"), Ok(int(255)));
+    assert_eq!(parse("0b1010"), Ok(int(10)));
+    assert_eq!(parse("-0B1010"), Ok(int(-10)));
+    assert_eq!(parse("0o77"), Ok(int(63)));
+    assert_eq!(parse("-0o77"), Ok(int(-63)));
+    assert_eq!(parse("1234"), Ok(int(1234)));
+    assert_eq!(parse("-1234"), Ok(int(-1234)));
+    assert_eq!(parse("0"), Ok(Int256::ZERO));
+    assert_eq!(parse(&format!("-0x8{}", "0".repeat(63))), Ok(Int256::MIN));
+
+    let kind = |s: &str| *parse(s).unwrap_err().kind();
+    assert_eq!(kind(&format!("0x8{}", "0".repeat(63))), IntErrorKind::PosOverflow);
+    assert_eq!(kind(&format!("-0x8{}1", "0".repeat(62))), IntErrorKind::NegOverflow);
+    assert_eq!(kind(&format!("0x1{}", "0".repeat(64))), IntErrorKind::PosOverflow);
+    assert_eq!(kind("0x"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("-0b102"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("0o8"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("--0x1"), IntErrorKind::InvalidDigit);
+    assert_eq!(kind(""), IntErrorKind::Empty);
+}
+
+#[quickcheck]
+fn int256_from_str_prefixed_i128(v: i128) -> bool {
+    let sign = if v < 0 { "-" } else { "" };
+    let m = v.unsigned_abs();
+    let x = Int256::from_i128(v);
+    [format!("{sign}{m:#x}"), format!("{sign}{m:#o}"), format!("{sign}{m:#b}"), v.to_string()]
+        .iter()
+        .all(|s| Int256::from_str_prefixed(s) == Ok(x))
+}
+
+#[quickcheck]
+fn uint256_widening_square(l0: u64, l1: u64, l2: u64, l3: u64, keep: u8) -> bool {
+    // Zero some limbs so carries out of sparse values are covered too.
+    let mask = |bit: u8, v: u64| if keep & bit != 0 { v } else { 0 };
+    let a = Uint256 { l0: mask(1, l0), l1: mask(2, l1), l2: mask(4, l2), l3: mask(8, l3) };
+    a.widening_square() == a.widening_mul(a) && a.square() == a * a
+}
+
+#[test]
+fn uint256_widening_square_edges() {
+    assert_eq!(Uint256::MAX.widening_square(), Uint256::MAX.widening_mul(Uint256::MAX));
+    assert_eq!(Uint256::MAX.square(), Uint256::ONE);
+    assert_eq!((Uint256::ONE << 255u32).widening_square(), (Uint256::ONE << 254u32, Uint256::ZERO));
+    assert_eq!(Uint256::ZERO.widening_square(), (Uint256::ZERO, Uint256::ZERO));
+    let all_limbs_max_but_low = Uint256::from_limbs([0, u64::MAX, u64::MAX, u64::MAX]);
+    assert_eq!(
+        all_limbs_max_but_low.widening_square(),
+        all_limbs_max_but_low.widening_mul(all_limbs_max_but_low)
+    );
+}
@@ -1,3 +1,5 @@
+use crate::error::TryFromIntError;
+use crate::u64::Uint64;
 use std::cmp::Ordering;
 
 /// 128-bit unsigned integer stored as two 64-bit limbs.
@@ -47,6 +49,337 @@ pub struct Uint128 {
     pub l: u64, // bits 0-63 (higher address)
 }
 
+impl Uint128 {
+    pub const ZERO: Self = Self { l: 0, h: 0 };
+    pub const ONE: Self = Self { l: 1, h: 0 };
+    pub const MIN: Self = Self::ZERO;
+    pub const MAX: Self = Self {
+        l: u64::MAX,
+        h: u64::MAX,
+    };
+
+    /// Little-endian byte representation, identical to `u128::to_le_bytes`.
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        ((self.h as u128) << 64 | self.l as u128).to_le_bytes()
+    }
+
+    /// Big-endian byte representation, identical to `u128::to_be_bytes`.
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        ((self.h as u128) << 64 | self.l as u128).to_be_bytes()
+    }
+
+    /// Create from little-endian bytes, identical to `u128::from_le_bytes`.
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let v = u128::from_le_bytes(bytes);
+        Self {
+            l: v as u64,
+            h: (v >> 64) as u64,
+        }
+    }
+
+    /// Create from big-endian bytes, identical to `u128::from_be_bytes`.
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let v = u128::from_be_bytes(bytes);
+        Self {
+            l: v as u64,
+            h: (v >> 64) as u64,
+        }
+    }
+
+    /// Native-endian byte representation, identical to `u128::to_ne_bytes`.
+    /// The field order follows `target_endian`, so this is also the
+    /// struct's own memory layout.
+    pub const fn to_ne_bytes(self) -> [u8; 16] {
+        if cfg!(target_endian = "big") { self.to_be_bytes() } else { self.to_le_bytes() }
+    }
+
+    /// Create from native-endian bytes, identical to `u128::from_ne_bytes`.
+    pub const fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        if cfg!(target_endian = "big") { Self::from_be_bytes(bytes) } else { Self::from_le_bytes(bytes) }
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.l.count_ones() + self.h.count_ones()
+    }
+
+    /// Number of clear bits.
+    pub fn count_zeros(&self) -> u32 {
+        self.l.count_zeros() + self.h.count_zeros()
+    }
+
+    /// Trailing zero bits. Zero yields the full width (128).
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.l != 0 {
+            self.l.trailing_zeros()
+        } else {
+            64 + self.h.trailing_zeros()
+        }
+    }
+
+    /// Trailing one bits. All-ones yields the full width (128).
+    pub fn trailing_ones(&self) -> u32 {
+        if self.l != u64::MAX {
+            self.l.trailing_ones()
+        } else {
+            64 + self.h.trailing_ones()
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.l == 0 && self.h == 0
+    }
+
+    /// True if the lowest bit is clear. Zero is even.
+    pub const fn is_even(&self) -> bool {
+        self.l & 1 == 0
+    }
+
+    /// True if the lowest bit is set.
+    pub const fn is_odd(&self) -> bool {
+        self.l & 1 == 1
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        if self.h != 0 {
+            self.h.leading_zeros()
+        } else {
+            64 + self.l.leading_zeros()
+        }
+    }
+
+    /// Leading one bits. All-ones yields the full width (128).
+    pub fn leading_ones(&self) -> u32 {
+        (!*self).leading_zeros()
+    }
+
+    /// Minimal number of bits needed to represent the value: 0 for zero,
+    /// otherwise `128 - leading_zeros()`.
+    pub fn bits(&self) -> u32 {
+        128 - self.leading_zeros()
+    }
+
+    /// Reverse the byte order, like `u128::swap_bytes`: the limbs trade
+    /// places and each is byte-swapped.
+    pub const fn swap_bytes(self) -> Self {
+        Self { l: self.h.swap_bytes(), h: self.l.swap_bytes() }
+    }
+
+    /// Reverse the bit order, like `u128::reverse_bits`.
+    pub const fn reverse_bits(self) -> Self {
+        Self { l: self.h.reverse_bits(), h: self.l.reverse_bits() }
+    }
+
+    /// True if exactly one bit is set.
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Smallest power of two greater than or equal to `self`.
+    ///
+    /// Wraps to `ZERO` when that power exceeds `MAX` (like native
+    /// `next_power_of_two` in release builds); `ZERO` rounds up to `ONE`.
+    pub fn next_power_of_two(self) -> Self {
+        if self <= Self::ONE {
+            return Self::ONE;
+        }
+        let p = 128 - (self - Self::ONE).leading_zeros();
+        if p == 128 { Self::ZERO } else { Self::ONE << p }
+    }
+
+    /// Addition returning the wrapped result and the carry out of the high limb.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (l, carry) = self.l.overflowing_add(rhs.l);
+        let (h, carry) = self.h.carrying_add(rhs.h, carry);
+        (Self { l, h }, carry)
+    }
+
+    /// Subtraction returning the wrapped result and the borrow out of the high limb.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (l, borrow) = self.l.overflowing_sub(rhs.l);
+        let (h, borrow) = self.h.borrowing_sub(rhs.h, borrow);
+        (Self { l, h }, borrow)
+    }
+
+    /// Multiplication returning the low 128 bits and whether the product
+    /// needed more than 128 bits.
+    ///
+    /// Same column layout as `Mul`, but every term that `Mul` discards is
+    /// checked: the `h×h` term overflows whenever both high limbs are nonzero,
+    /// and the cross terms must fit in 64 bits and join `p0_hi` without carry.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (p0_hi, p0_lo) = Self::mul_u64_wide(self.l, rhs.l);
+        let (t1_lo, o1) = self.l.overflowing_mul(rhs.h);
+        let (t2_lo, o2) = self.h.overflowing_mul(rhs.l);
+        let (h, o3) = p0_hi.overflowing_add(t1_lo);
+        let (h, o4) = h.overflowing_add(t2_lo);
+        let overflow = (self.h != 0 && rhs.h != 0) | o1 | o2 | o3 | o4;
+        (Self { l: p0_lo, h }, overflow)
+    }
+
+    /// Checked addition. Returns `None` on carry out of the high limb.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on borrow out of the high limb.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` if the product needs more than
+    /// 128 bits.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self / rhs) }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self % rhs) }
+    }
+
+    /// Wrapping division. Unsigned division cannot overflow, so this is
+    /// `self / rhs`, provided for parity with `u128::wrapping_div`.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Wrapping remainder; the same as `self % rhs`, like
+    /// `u128::wrapping_rem`.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
+    /// Saturating addition. Clamps to `MAX` on overflow.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Saturating subtraction. Clamps to `ZERO` on underflow.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Self::ZERO)
+    }
+
+    /// Saturating multiplication. Clamps to `MAX` on overflow.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Wrapping exponentiation by squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    /// Checked exponentiation. Returns `None` if the result exceeds `MAX`.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(base)?;
+            }
+            exp >>= 1;
+            // Skip the final squaring: it is never used and may overflow.
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Two's complement negation, `0 - self`, like `u128::wrapping_neg`.
+    pub fn wrapping_neg(self) -> Self {
+        Self::ZERO - self
+    }
+
+    /// Absolute difference `|self - other|`, computed as larger minus
+    /// smaller so it never wraps.
+    pub fn abs_diff(self, other: Self) -> Self {
+        if self > other { self - other } else { other - self }
+    }
+
+    /// Smallest multiple of `rhs` that is `>= self`, like
+    /// `u128::next_multiple_of`. Adds `rhs - self % rhs` rather than
+    /// rounding `self + rhs - 1`, so only a result above `MAX` wraps; use
+    /// `checked_next_multiple_of` to detect that. Panics if `rhs` is zero.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_zero() { self } else { self + (rhs - r) }
+    }
+
+    /// Checked `next_multiple_of`. Returns `None` if `rhs` is zero or the
+    /// result does not fit.
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        let r = self.checked_rem(rhs)?;
+        if r.is_zero() { Some(self) } else { self.checked_add(rhs - r) }
+    }
+
+    /// Full 64×64→128 product as `(high, low)`.
+    ///
+    /// Uses `mulx` when BMI2 is enabled at compile time and the native
+    /// u128 multiply elsewhere.
+    #[inline]
+    pub fn mul_u64_wide(a: u64, b: u64) -> (u64, u64) {
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        {
+            let mut hi = 0u64;
+            // SAFETY: `bmi2` is statically enabled for this build.
+            let lo = unsafe { core::arch::x86_64::_mulx_u64(a, b, &mut hi) };
+            (hi, lo)
+        }
+
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+        {
+            let p = a as u128 * b as u128;
+            ((p >> 64) as u64, p as u64)
+        }
+    }
+
+    /// Full 256-bit product split into `(high, low)` halves.
+    ///
+    /// Four 64×64→128 partial products. The middle column collects the high
+    /// half of `l×l` plus the low halves of both cross terms (at most
+    /// 3·(2^64-1), so it fits in u128); its carry joins the top column.
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let (p00_hi, p00_lo) = Self::mul_u64_wide(self.l, rhs.l);
+        let (p01_hi, p01_lo) = Self::mul_u64_wide(self.l, rhs.h);
+        let (p10_hi, p10_lo) = Self::mul_u64_wide(self.h, rhs.l);
+        let (p11_hi, p11_lo) = Self::mul_u64_wide(self.h, rhs.h);
+
+        let mid = p00_hi as u128 + p01_lo as u128 + p10_lo as u128;
+        let top = p11_lo as u128 + p01_hi as u128 + p10_hi as u128 + (mid >> 64);
+
+        let lo = Self {
+            l: p00_lo,
+            h: mid as u64,
+        };
+        let hi = Self {
+            l: top as u64,
+            h: p11_hi + (top >> 64) as u64,
+        };
+        (hi, lo)
+    }
+}
+
 impl std::ops::Add for Uint128 {
     type Output = Self;
 
@@ -105,13 +438,14 @@ impl std::ops::Mul for Uint128 {
     ///
     /// # 64×64→128 multiplication
     ///
-    /// Uses `u64::widening_mul` (nightly `bigint_helper_methods`) for the full-width
-    /// multiply. LLVM lowers this to the optimal instruction on each platform:
+    /// Uses `mul_u64_wide` for the full-width multiply: `mulx` when BMI2 is
+    /// enabled, otherwise the native u128 product, which LLVM lowers to the
+    /// optimal instruction on each platform:
     /// - x86_64: `mulx` (BMI2) or `mul`
     /// - aarch64: `mul` + `umulh`
     /// - riscv64: `mul` + `mulhu`
     fn mul(self, rhs: Self) -> Self::Output {
-        let (p0_lo, p0_hi) = self.l.widening_mul(rhs.l);
+        let (p0_hi, p0_lo) = Self::mul_u64_wide(self.l, rhs.l);
 
         let t1_lo = self.l.wrapping_mul(rhs.h);
         let t2_lo = self.h.wrapping_mul(rhs.l);
@@ -206,6 +540,15 @@ impl std::ops::Rem for Uint128 {
     }
 }
 
+forward_assign_ops!(Uint128);
+forward_ref_binops!(Uint128);
+
+impl Default for Uint128 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
 impl PartialEq for Uint128 {
     fn eq(&self, other: &Self) -> bool {
         self.h == other.h && self.l == other.l
@@ -228,3 +571,149 @@ impl Ord for Uint128 {
         }
     }
 }
+
+ord_methods!(Uint128);
+
+widening_cmp!(Uint128, Uint64);
+
+impl std::ops::Not for Uint128 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self {
+            l: !self.l,
+            h: !self.h,
+        }
+    }
+}
+
+impl std::ops::BitAnd for Uint128 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            l: self.l & rhs.l,
+            h: self.h & rhs.h,
+        }
+    }
+}
+
+impl std::ops::BitOr for Uint128 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            l: self.l | rhs.l,
+            h: self.h | rhs.h,
+        }
+    }
+}
+
+impl std::ops::BitXor for Uint128 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self {
+            l: self.l ^ rhs.l,
+            h: self.h ^ rhs.h,
+        }
+    }
+}
+
+impl std::ops::Shl<u32> for Uint128 {
+    type Output = Self;
+
+    fn shl(self, n: u32) -> Self::Output {
+        if n >= 128 {
+            Self::ZERO
+        } else if n >= 64 {
+            Self {
+                l: 0,
+                h: self.l << (n - 64),
+            }
+        } else if n == 0 {
+            self
+        } else {
+            Self {
+                l: self.l << n,
+                h: (self.h << n) | (self.l >> (64 - n)),
+            }
+        }
+    }
+}
+
+impl std::ops::Shr<u32> for Uint128 {
+    type Output = Self;
+
+    /// Logical right shift: fills with zeros.
+    fn shr(self, n: u32) -> Self::Output {
+        if n >= 128 {
+            Self::ZERO
+        } else if n >= 64 {
+            Self {
+                l: self.h >> (n - 64),
+                h: 0,
+            }
+        } else if n == 0 {
+            self
+        } else {
+            Self {
+                l: (self.l >> n) | (self.h << (64 - n)),
+                h: self.h >> n,
+            }
+        }
+    }
+}
+
+forward_bit_assign_ops!(Uint128);
+shift_methods!(Uint128, 128);
+
+/// Zero-extends into the low limb.
+impl From<Uint64> for Uint128 {
+    fn from(v: Uint64) -> Self {
+        Self {
+            l: v.to_u64(),
+            h: 0,
+        }
+    }
+}
+
+/// Fails unless the high limb is zero.
+impl TryFrom<Uint128> for Uint64 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint128) -> Result<Self, Self::Error> {
+        if v.h == 0 {
+            Ok(Uint64::from_u64(v.l))
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+impl From<u128> for Uint128 {
+    fn from(v: u128) -> Self {
+        Self {
+            l: v as u64,
+            h: (v >> 64) as u64,
+        }
+    }
+}
+
+impl From<u64> for Uint128 {
+    fn from(v: u64) -> Self {
+        Self { l: v, h: 0 }
+    }
+}
+
+impl From<u32> for Uint128 {
+    fn from(v: u32) -> Self {
+        Self::from(v as u64)
+    }
+}
+
+impl From<u8> for Uint128 {
+    fn from(v: u8) -> Self {
+        Self::from(v as u64)
+    }
+}
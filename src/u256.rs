@@ -1,3 +1,8 @@
+use crate::error::{IntErrorKind, ParseIntError, TryFromIntError, TryFromSliceError};
+use crate::i256::Int256;
+use crate::u64::Uint64;
+use crate::u128::Uint128;
+use crate::u512::divrem_limbs;
 use std::cmp::Ordering;
 
 /// 256-bit unsigned integer stored as four 64-bit limbs.
@@ -35,10 +40,170 @@ impl Uint256 {
         l2: 0,
         l3: 0,
     };
+    pub const ONE: Self = Self {
+        l0: 1,
+        l1: 0,
+        l2: 0,
+        l3: 0,
+    };
+    pub const MIN: Self = Self::ZERO;
+    pub const MAX: Self = Self {
+        l0: u64::MAX,
+        l1: u64::MAX,
+        l2: u64::MAX,
+        l3: u64::MAX,
+    };
+
+    /// Create from limbs in logical order, least significant first. Unlike
+    /// a struct literal this does not depend on the field order, which
+    /// flips on big-endian targets.
+    ///
+    /// This is the order of ark-ff's `BigInt<4>` and most other field
+    /// element libraries, so their `[u64; 4]` limbs pass through unchanged.
+    pub const fn from_limbs(limbs: [u64; 4]) -> Self {
+        Self {
+            l0: limbs[0],
+            l1: limbs[1],
+            l2: limbs[2],
+            l3: limbs[3],
+        }
+    }
+
+    /// Limbs in logical order, least significant first, as ark-ff's
+    /// `BigInt<4>` stores them.
+    pub const fn to_limbs(self) -> [u64; 4] {
+        [self.l0, self.l1, self.l2, self.l3]
+    }
+
+    /// Create from 64-bit words most significant first, the order EVM
+    /// tooling uses. This is `from_limbs` with the array reversed.
+    pub const fn from_be_words(words: [u64; 4]) -> Self {
+        Self {
+            l0: words[3],
+            l1: words[2],
+            l2: words[1],
+            l3: words[0],
+        }
+    }
+
+    /// 64-bit words most significant first.
+    pub const fn to_be_words(self) -> [u64; 4] {
+        [self.l3, self.l2, self.l1, self.l0]
+    }
+
+    /// Create from a u128, zeroing the upper two limbs.
+    pub const fn from_u128(v: u128) -> Self {
+        Self {
+            l0: v as u64,
+            l1: (v >> 64) as u64,
+            l2: 0,
+            l3: 0,
+        }
+    }
+
+    /// Low 128 bits as a u128. The upper two limbs are discarded.
+    pub const fn to_u128(self) -> u128 {
+        (self.l1 as u128) << 64 | self.l0 as u128
+    }
+
+    /// Convert to u128, or `None` if either upper limb is non-zero.
+    pub const fn try_to_u128(self) -> Option<u128> {
+        if self.l2 == 0 && self.l3 == 0 {
+            Some(self.to_u128())
+        } else {
+            None
+        }
+    }
+
+    /// Convert to u128, clamping values above `u128::MAX` to `u128::MAX`.
+    pub const fn to_u128_saturating(self) -> u128 {
+        match self.try_to_u128() {
+            Some(v) => v,
+            None => u128::MAX,
+        }
+    }
+
+    /// Little-endian byte representation: `l0`'s bytes first, least
+    /// significant first.
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        let limbs = [self.l0, self.l1, self.l2, self.l3];
+        let mut out = [0u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            out[i] = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+            i += 1;
+        }
+        out
+    }
+
+    /// Big-endian byte representation, most significant byte first.
+    pub const fn to_be_bytes(self) -> [u8; 32] {
+        self.swap_bytes().to_le_bytes()
+    }
+
+    /// Native-endian byte representation. The field order follows
+    /// `target_endian`, so this is also the struct's own memory layout.
+    pub const fn to_ne_bytes(self) -> [u8; 32] {
+        if cfg!(target_endian = "big") { self.to_be_bytes() } else { self.to_le_bytes() }
+    }
+
+    /// Create from little-endian bytes.
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut i = 0;
+        while i < 32 {
+            limbs[i / 8] |= (bytes[i] as u64) << (8 * (i % 8));
+            i += 1;
+        }
+        Self { l0: limbs[0], l1: limbs[1], l2: limbs[2], l3: limbs[3] }
+    }
+
+    /// Create from big-endian bytes.
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        Self::from_le_bytes(bytes).swap_bytes()
+    }
+
+    /// Create from native-endian bytes.
+    pub const fn from_ne_bytes(bytes: [u8; 32]) -> Self {
+        if cfg!(target_endian = "big") { Self::from_be_bytes(bytes) } else { Self::from_le_bytes(bytes) }
+    }
+
+    /// Create from up to 32 big-endian bytes, zero-extending shorter
+    /// slices, so minimal encodings with leading zeros dropped read back
+    /// directly. Fails only if the slice is longer than 32 bytes.
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, TryFromSliceError> {
+        if bytes.len() > 32 {
+            return Err(TryFromSliceError(()));
+        }
+        let mut buf = [0u8; 32];
+        buf[32 - bytes.len()..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(buf))
+    }
+
+    /// Create from up to 32 little-endian bytes, zero-extending shorter
+    /// slices. Fails only if the slice is longer than 32 bytes.
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, TryFromSliceError> {
+        if bytes.len() > 32 {
+            return Err(TryFromSliceError(()));
+        }
+        let mut buf = [0u8; 32];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self::from_le_bytes(buf))
+    }
 
     pub fn is_zero(&self) -> bool {
         self.l0 == 0 && self.l1 == 0 && self.l2 == 0 && self.l3 == 0
     }
+
+    /// True if the lowest bit is clear. Zero is even.
+    pub const fn is_even(&self) -> bool {
+        self.l0 & 1 == 0
+    }
+
+    /// True if the lowest bit is set.
+    pub const fn is_odd(&self) -> bool {
+        self.l0 & 1 == 1
+    }
 }
 
 impl std::ops::Add for Uint256 {
@@ -48,26 +213,18 @@ impl std::ops::Add for Uint256 {
     /// this is much better generated code than previously (uint) pre 1.79
     #[inline(never)]
     fn add(self, rhs: Self) -> Self::Output {
-        let (l0, c0) = self.l0.overflowing_add(rhs.l0);
-
-        let (l1, c1) = self.l1.carrying_add(rhs.l1, c0);
-        let (l2, c2) = self.l2.carrying_add(rhs.l2, c1);
-        let (l3, _) = self.l3.carrying_add(rhs.l3, c2);
-        Self { l0, l1, l2, l3 }
+        self.wrapping_add(rhs)
     }
 }
 
 impl std::ops::Sub for Uint256 {
     type Output = Self;
 
-    /// 256-bit subtraction with borrow chain.
+    /// 256-bit subtraction with borrow chain. Wraps on underflow in every
+    /// build profile; use `checked_sub` or `overflowing_sub` to detect it.
     #[inline(never)]
     fn sub(self, rhs: Self) -> Self::Output {
-        let (l0, b0) = self.l0.overflowing_sub(rhs.l0);
-        let (l1, b1) = self.l1.borrowing_sub(rhs.l1, b0);
-        let (l2, b2) = self.l2.borrowing_sub(rhs.l2, b1);
-        let (l3, _) = self.l3.borrowing_sub(rhs.l3, b2);
-        Self { l0, l1, l2, l3 }
+        self.wrapping_sub(rhs)
     }
 }
 
@@ -75,25 +232,24 @@ impl std::ops::Mul for Uint256 {
     type Output = Self;
 
     /// 256-bit multiplication (schoolbook), keeping only the low 256 bits.
+    ///
+    /// Every path here runs on the baseline ISA of its target; on x86_64 it
+    /// never needs BMI2, so no runtime feature check is required. Use
+    /// `optimal_u256_mul` for the BMI2 kernel with its own detection.
     #[inline(never)]
     fn mul(self, rhs: Self) -> Self::Output {
-        #[cfg(target_arch = "x86_64")]
-        {
-            Self::mul_adx(self, rhs)
-        }
-
-        #[cfg(not(target_arch = "x86_64"))]
-        self.mul_portable(rhs)
+        self.wrapping_mul(rhs)
     }
 }
 
 impl Uint256 {
-    /// Portable multiplication fallback using u128.
+    /// Wrapping multiplication (schoolbook over u128 columns), usable in
+    /// const context. This is what `Mul` runs; LLVM generates good code for
+    /// the u128 arithmetic.
     ///
     /// Tracks overflow when column sums exceed u128 to ensure correct carry
     /// propagation for all input values.
-    #[cfg(not(target_arch = "x86_64"))]
-    fn mul_portable(self, rhs: Self) -> Self {
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
         let (a0, a1, a2, a3) = (self.l0, self.l1, self.l2, self.l3);
         let (b0, b1, b2, b3) = (rhs.l0, rhs.l1, rhs.l2, rhs.l3);
 
@@ -129,54 +285,323 @@ impl Uint256 {
 
         Self { l0: r0, l1: r1, l2: r2, l3: r3 }
     }
+}
 
-    /// x86_64 optimized multiplication using u128 intermediates.
-    /// LLVM tends to generate good code for u128 arithmetic on x86_64.
+impl Uint256 {
+    /// Wrapping addition, usable in const context.
     ///
-    /// Tracks overflow when column sums exceed u128 to ensure correct carry
-    /// propagation for all input values.
-    #[cfg(target_arch = "x86_64")]
-    #[inline]
-    fn mul_adx(self, rhs: Self) -> Self {
-        let (a0, a1, a2, a3) = (self.l0, self.l1, self.l2, self.l3);
-        let (b0, b1, b2, b3) = (rhs.l0, rhs.l1, rhs.l2, rhs.l3);
+    /// The carry chain uses `overflowing_add` alone because the nightly
+    /// `carrying_add` is not const-stable; LLVM still lowers it to one `add`
+    /// and three `adc`s.
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        let (l0, c0) = self.l0.overflowing_add(rhs.l0);
+        let (l1, c1a) = self.l1.overflowing_add(rhs.l1);
+        let (l1, c1b) = l1.overflowing_add(c0 as u64);
+        let (l2, c2a) = self.l2.overflowing_add(rhs.l2);
+        let (l2, c2b) = l2.overflowing_add((c1a | c1b) as u64);
+        let l3 = self.l3.wrapping_add(rhs.l3).wrapping_add((c2a | c2b) as u64);
+        Self { l0, l1, l2, l3 }
+    }
 
-        // Column 0: single product, no overflow possible
-        let p00 = (a0 as u128) * (b0 as u128);
-        let r0 = p00 as u64;
-        let mut carry = p00 >> 64;
+    /// Wrapping subtraction, usable in const context. Borrow chain written
+    /// like `wrapping_add`.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        let (l0, b0) = self.l0.overflowing_sub(rhs.l0);
+        let (l1, b1a) = self.l1.overflowing_sub(rhs.l1);
+        let (l1, b1b) = l1.overflowing_sub(b0 as u64);
+        let (l2, b2a) = self.l2.overflowing_sub(rhs.l2);
+        let (l2, b2b) = l2.overflowing_sub((b1a | b1b) as u64);
+        let l3 = self.l3.wrapping_sub(rhs.l3).wrapping_sub((b2a | b2b) as u64);
+        Self { l0, l1, l2, l3 }
+    }
 
-        // Column 1: two products + carry
-        // Track overflow when sum exceeds u128
-        let p01 = (a0 as u128) * (b1 as u128);
-        let p10 = (a1 as u128) * (b0 as u128);
-        let (sum, o1) = carry.overflowing_add(p01);
-        let (col1, o2) = sum.overflowing_add(p10);
-        let r1 = col1 as u64;
-        // Carry includes overflow: each overflow adds 2^128, which is 2^64 in the next column's scale
-        carry = (col1 >> 64) + ((((o1 as u64) + (o2 as u64)) as u128) << 64);
+    /// Addition returning the wrapped result and the carry out of `l3`.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (l0, c0) = self.l0.overflowing_add(rhs.l0);
+        let (l1, c1) = self.l1.carrying_add(rhs.l1, c0);
+        let (l2, c2) = self.l2.carrying_add(rhs.l2, c1);
+        let (l3, c3) = self.l3.carrying_add(rhs.l3, c2);
+        (Self { l0, l1, l2, l3 }, c3)
+    }
 
-        // Column 2: three products + carry
-        let p02 = (a0 as u128) * (b2 as u128);
-        let p11 = (a1 as u128) * (b1 as u128);
-        let p20 = (a2 as u128) * (b0 as u128);
-        let (sum, o1) = carry.overflowing_add(p02);
-        let (sum, o2) = sum.overflowing_add(p11);
-        let (col2, o3) = sum.overflowing_add(p20);
-        let r2 = col2 as u64;
-        carry = (col2 >> 64) + ((((o1 as u64) + (o2 as u64) + (o3 as u64)) as u128) << 64);
+    /// Subtraction returning the wrapped result and the borrow out of `l3`.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (l0, b0) = self.l0.overflowing_sub(rhs.l0);
+        let (l1, b1) = self.l1.borrowing_sub(rhs.l1, b0);
+        let (l2, b2) = self.l2.borrowing_sub(rhs.l2, b1);
+        let (l3, b3) = self.l3.borrowing_sub(rhs.l3, b2);
+        (Self { l0, l1, l2, l3 }, b3)
+    }
 
-        // Column 3: only need low 64 bits, overflow discarded
-        let r3 = (carry as u64)
-            .wrapping_add(a0.wrapping_mul(b3))
-            .wrapping_add(a1.wrapping_mul(b2))
-            .wrapping_add(a2.wrapping_mul(b1))
-            .wrapping_add(a3.wrapping_mul(b0));
+    /// `self + rhs + carry`, returning the wrapped sum and the carry out of
+    /// `l3`. Chaining the carry across several `Uint256`s builds wider
+    /// additions, like `u64::carrying_add` does for limbs.
+    pub fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let (l0, c0) = self.l0.carrying_add(rhs.l0, carry);
+        let (l1, c1) = self.l1.carrying_add(rhs.l1, c0);
+        let (l2, c2) = self.l2.carrying_add(rhs.l2, c1);
+        let (l3, c3) = self.l3.carrying_add(rhs.l3, c2);
+        (Self { l0, l1, l2, l3 }, c3)
+    }
 
-        Self { l0: r0, l1: r1, l2: r2, l3: r3 }
+    /// `self + rhs` as a 257-bit result: the low 256 bits and the carry.
+    /// The same as `overflowing_add`, named for accumulation loops where
+    /// the carry is the next limb rather than an error.
+    pub fn carrying_add_bit(self, rhs: Self) -> (Self, bool) {
+        self.overflowing_add(rhs)
+    }
+
+    /// `self - rhs - borrow`, returning the wrapped difference and the
+    /// borrow out of `l3`. The subtraction counterpart of `carrying_add`.
+    pub fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let (l0, b0) = self.l0.borrowing_sub(rhs.l0, borrow);
+        let (l1, b1) = self.l1.borrowing_sub(rhs.l1, b0);
+        let (l2, b2) = self.l2.borrowing_sub(rhs.l2, b1);
+        let (l3, b3) = self.l3.borrowing_sub(rhs.l3, b2);
+        (Self { l0, l1, l2, l3 }, b3)
+    }
+
+    /// Multiplication returning the low 256 bits and whether any product bit
+    /// landed above bit 255 (the bits `Mul` discards).
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let p = self.mul_wide(rhs);
+        let low = Self {
+            l0: p[0],
+            l1: p[1],
+            l2: p[2],
+            l3: p[3],
+        };
+        (low, p[4] | p[5] | p[6] | p[7] != 0)
+    }
+
+    /// Checked addition. Returns `None` on carry out of `l3`.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on borrow out of `l3`.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked addition of a signed delta, like `u128::checked_add_signed`.
+    /// The two's complement sum is bit-identical; a carry out means overflow
+    /// for a non-negative `rhs`, and a missing one means the negative `rhs`
+    /// took the result below zero.
+    pub fn checked_add_signed(self, rhs: Int256) -> Option<Self> {
+        let (result, carry) = self.overflowing_add(rhs.to_uint256());
+        if carry != rhs.is_negative() { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` if any product bit lands
+    /// above bit 255.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self / rhs) }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self % rhs) }
+    }
+
+    /// Wrapping division. Unsigned division cannot overflow, so this is
+    /// `self / rhs`, provided for parity with `u128::wrapping_div`.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Wrapping remainder; the same as `self % rhs`, like
+    /// `u128::wrapping_rem`.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
+    /// Saturating addition. Clamps to `MAX` on overflow.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Saturating subtraction. Clamps to `ZERO` on underflow.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Self::ZERO)
+    }
+
+    /// Saturating multiplication. Clamps to `MAX` on overflow.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Wrapping exponentiation by squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    /// Checked exponentiation. Returns `None` if the result exceeds `MAX`.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(base)?;
+            }
+            exp >>= 1;
+            // Skip the final squaring: it is never used and may overflow.
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Two's complement negation, `0 - self`, like `u128::wrapping_neg`.
+    pub fn wrapping_neg(self) -> Self {
+        Self::ZERO - self
+    }
+
+    /// Absolute difference `|self - other|`, computed as larger minus
+    /// smaller so it never wraps.
+    pub fn abs_diff(self, other: Self) -> Self {
+        if self > other { self - other } else { other - self }
+    }
+
+    /// Smallest multiple of `rhs` that is `>= self`, like
+    /// `u128::next_multiple_of`. Adds `rhs - self % rhs` rather than
+    /// rounding `self + rhs - 1`, so only a result above `MAX` wraps; use
+    /// `checked_next_multiple_of` to detect that. Panics if `rhs` is zero.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_zero() { self } else { self + (rhs - r) }
+    }
+
+    /// Checked `next_multiple_of`. Returns `None` if `rhs` is zero or the
+    /// result does not fit.
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        let r = self.checked_rem(rhs)?;
+        if r.is_zero() { Some(self) } else { self.checked_add(rhs - r) }
+    }
+
+    /// Full 512-bit product split into `(high, low)` halves.
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let p = self.mul_wide(rhs);
+        let lo = Self { l0: p[0], l1: p[1], l2: p[2], l3: p[3] };
+        let hi = Self { l0: p[4], l1: p[5], l2: p[6], l3: p[7] };
+        (hi, lo)
+    }
+
+    /// High 256 bits of the 512-bit product, the half `*` discards. This is
+    /// `x * r / 2^256` for fixed-point multiplication by a reciprocal `r`.
+    pub fn mulhi(self, rhs: Self) -> Self {
+        self.widening_mul(rhs).0
+    }
+
+    /// Full 512-bit square split into `(high, low)` halves, the same as
+    /// `widening_mul(self, self)`.
+    ///
+    /// Each cross product `a[i] * a[j]` with `i != j` appears twice in the
+    /// square, so it is computed once and the sum doubled with a one-bit
+    /// shift, then the four diagonal squares are added in. That is 10 limb
+    /// products instead of 16.
+    pub fn widening_square(self) -> (Self, Self) {
+        let a = [self.l0, self.l1, self.l2, self.l3];
+        let mut r = [0u64; 8];
+
+        for i in 0..3 {
+            let mut carry = 0u64;
+            for j in i + 1..4 {
+                let t = (a[i] as u128) * (a[j] as u128) + r[i + j] as u128 + carry as u128;
+                r[i + j] = t as u64;
+                carry = (t >> 64) as u64;
+            }
+            r[i + 4] = carry;
+        }
+
+        // The cross terms sum to less than half the square, below 2^511, so
+        // doubling them shifts nothing out of r[7].
+        for k in (1..8).rev() {
+            r[k] = r[k] << 1 | r[k - 1] >> 63;
+        }
+        r[0] <<= 1;
+
+        // The square is below 2^512, so the last carry is always zero.
+        let mut carry = 0u64;
+        for i in 0..4 {
+            let sq = (a[i] as u128) * (a[i] as u128);
+            let t = r[2 * i] as u128 + (sq as u64) as u128 + carry as u128;
+            r[2 * i] = t as u64;
+            let t = r[2 * i + 1] as u128 + (sq >> 64) + (t >> 64);
+            r[2 * i + 1] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+
+        let lo = Self { l0: r[0], l1: r[1], l2: r[2], l3: r[3] };
+        let hi = Self { l0: r[4], l1: r[5], l2: r[6], l3: r[7] };
+        (hi, lo)
+    }
+
+    /// `self * self`, wrapping at 256 bits: the low half of
+    /// `widening_square`.
+    pub fn square(self) -> Self {
+        self.widening_square().1
+    }
+
+    /// Low 256 bits of the product and whether the high 256 bits are
+    /// nonzero, read off `widening_mul`.
+    ///
+    /// Returns the same pair as `overflowing_mul`: that flag is also set
+    /// exactly when a product bit lands above bit 255. This spelling makes
+    /// the "did the full product fit" question explicit at call sites.
+    pub fn overflowing_mul_full(self, rhs: Self) -> (Self, bool) {
+        let (hi, lo) = self.widening_mul(rhs);
+        (lo, !hi.is_zero())
+    }
+
+    /// Full 256×256→512 schoolbook product as eight limbs, least significant first.
+    fn mul_wide(self, rhs: Self) -> [u64; 8] {
+        let a = [self.l0, self.l1, self.l2, self.l3];
+        let b = [rhs.l0, rhs.l1, rhs.l2, rhs.l3];
+        let mut r = [0u64; 8];
+
+        for i in 0..4 {
+            let mut carry = 0u64;
+            for j in 0..4 {
+                // a*b + r + carry <= (2^64-1)^2 + 2*(2^64-1) = 2^128 - 1, never overflows
+                let t = (a[i] as u128) * (b[j] as u128) + r[i + j] as u128 + carry as u128;
+                r[i + j] = t as u64;
+                carry = (t >> 64) as u64;
+            }
+            r[i + 4] = carry;
+        }
+
+        r
+    }
+}
+
+impl Default for Uint256 {
+    fn default() -> Self {
+        Self::ZERO
     }
 }
 
+/// Not constant-time; see `Uint256::ct_eq`.
 impl PartialEq for Uint256 {
     fn eq(&self, other: &Self) -> bool {
         self.l0 == other.l0 && self.l1 == other.l1 && self.l2 == other.l2 && self.l3 == other.l3
@@ -206,6 +631,145 @@ impl Ord for Uint256 {
     }
 }
 
+ord_methods!(Uint256);
+
+widening_cmp!(Uint256, Uint64);
+widening_cmp!(Uint256, Uint128);
+
+impl std::ops::Not for Uint256 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self {
+            l0: !self.l0,
+            l1: !self.l1,
+            l2: !self.l2,
+            l3: !self.l3,
+        }
+    }
+}
+
+impl std::ops::BitAnd for Uint256 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            l0: self.l0 & rhs.l0,
+            l1: self.l1 & rhs.l1,
+            l2: self.l2 & rhs.l2,
+            l3: self.l3 & rhs.l3,
+        }
+    }
+}
+
+impl std::ops::BitOr for Uint256 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            l0: self.l0 | rhs.l0,
+            l1: self.l1 | rhs.l1,
+            l2: self.l2 | rhs.l2,
+            l3: self.l3 | rhs.l3,
+        }
+    }
+}
+
+impl std::ops::BitXor for Uint256 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self {
+            l0: self.l0 ^ rhs.l0,
+            l1: self.l1 ^ rhs.l1,
+            l2: self.l2 ^ rhs.l2,
+            l3: self.l3 ^ rhs.l3,
+        }
+    }
+}
+
+impl std::ops::Shl<u32> for Uint256 {
+    type Output = Self;
+
+    fn shl(self, n: u32) -> Self::Output {
+        self.shl_u32(n)
+    }
+}
+
+impl std::ops::Shr<u32> for Uint256 {
+    type Output = Self;
+
+    /// Logical right shift: fills with zeros.
+    fn shr(self, n: u32) -> Self::Output {
+        if n >= 256 {
+            return Self::ZERO;
+        }
+        if n == 0 {
+            return self;
+        }
+
+        let full_limbs = (n / 64) as usize;
+        let bits = n % 64;
+
+        let mut result = [0u64; 4];
+        let limbs = [self.l0, self.l1, self.l2, self.l3];
+
+        if bits == 0 {
+            result[..4 - full_limbs].copy_from_slice(&limbs[full_limbs..]);
+        } else {
+            for i in 0..(4 - full_limbs) {
+                result[i] = limbs[i + full_limbs] >> bits;
+                if i + full_limbs + 1 < 4 {
+                    result[i] |= limbs[i + full_limbs + 1] << (64 - bits);
+                }
+            }
+        }
+
+        Self { l0: result[0], l1: result[1], l2: result[2], l3: result[3] }
+    }
+}
+
+forward_bit_assign_ops!(Uint256);
+forward_shift_ops!(Uint256);
+shift_methods!(Uint256, 256);
+
+impl Uint256 {
+    /// `self * 2^k`, wrapping at 256 bits. A left shift, so `k >= 256`
+    /// gives zero just as the full product would modulo 2^256.
+    pub fn mul_pow2(self, k: u32) -> Self {
+        self << k
+    }
+
+    /// `self / 2^k`, rounding down. A logical right shift; `k >= 256`
+    /// gives zero.
+    pub fn div_pow2(self, k: u32) -> Self {
+        self >> k
+    }
+
+    /// Left shift that also reports whether any set bit was shifted out of
+    /// the top, i.e. whether the result is no longer `self * 2^n`.
+    ///
+    /// Unlike native `overflowing_shl`, which only flags `n >= BITS` and
+    /// masks the shift, the shift is not masked: `n >= 256` gives zero, and
+    /// is lossy exactly when `self` is non-zero.
+    pub fn shl_lossless(self, n: u32) -> (Self, bool) {
+        (self << n, n.min(256) > self.leading_zeros())
+    }
+
+    /// `(self << shift) + add`, wrapping at 256 bits. One step of
+    /// accumulating base-2^k digits, as in `acc = acc * 16 + digit`. The
+    /// addend only touches the low limb, so the carry chain is a single
+    /// pass over the shifted limbs.
+    pub fn shl_add(self, shift: u32, add: u64) -> Self {
+        let s = self << shift;
+        let (l0, c0) = s.l0.overflowing_add(add);
+        let (l1, c1) = s.l1.overflowing_add(c0 as u64);
+        let (l2, c2) = s.l2.overflowing_add(c1 as u64);
+        let l3 = s.l3.wrapping_add(c2 as u64);
+        Self { l0, l1, l2, l3 }
+    }
+}
+
 impl std::ops::Div for Uint256 {
     type Output = Self;
 
@@ -221,44 +785,191 @@ impl std::ops::Div for Uint256 {
     ///
     /// 3. **General case (Knuth Algorithm D)**: Normalize divisor, estimate
     ///    quotient digits using top limbs, correct estimates.
+    ///
+    /// Shares its dispatch with `div_rem` through `div_rem_inline`; inlining
+    /// it here lets LLVM drop the remainder work, keeping `/` quotient-only.
     fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem_inline(rhs).0
+    }
+}
+
+impl std::ops::Rem for Uint256 {
+    type Output = Self;
+
+    /// Remainder from the same pass that produces the quotient.
+    ///
+    /// A u64 divisor (including zero) goes through `rem_u64`, so a zero
+    /// divisor panics from native `%` like u128 does.
+    fn rem(self, rhs: Self) -> Self::Output {
+        if rhs.l3 == 0 && rhs.l2 == 0 && rhs.l1 == 0 {
+            Self::from_u64(self.rem_u64(rhs.l0))
+        } else {
+            self.div_rem_inline(rhs).1
+        }
+    }
+}
+
+forward_assign_ops!(Uint256);
+forward_ref_binops!(Uint256);
+
+impl Uint256 {
+    /// Quotient and remainder from a single division pass.
+    ///
+    /// Every path already ends with the remainder in hand (the last limb's
+    /// remainder for the u64 path, the normalized remainder for Knuth), so
+    /// this costs little more than `/` alone.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        self.div_rem_inline(rhs)
+    }
+
+    /// Body of `div_rem`, forced inline so `/` and `%` each keep only the
+    /// half of the result they return.
+    #[inline(always)]
+    fn div_rem_inline(self, rhs: Self) -> (Self, Self) {
         // Dispatch based on divisor size for optimal codegen
         if rhs.l3 == 0 && rhs.l2 == 0 {
             if rhs.l1 == 0 {
                 // Divisor fits in u64 - use simple long division
-                self.div_by_u64(rhs.l0)
+                let (q, r) = self.divrem_by_u64(rhs.l0);
+                (q, Self::from_u64(r))
             } else {
                 // Divisor fits in u128
-                let d = (rhs.l1 as u128) << 64 | rhs.l0 as u128;
-                self.div_by_u128(d)
+                let (q, r) = self.divrem_by_u128(rhs.to_u128());
+                (q, Self::from_u128(r))
             }
         } else {
             // Full 256-bit divisor - use Knuth Algorithm D
             self.div_knuth(rhs)
         }
     }
-}
 
-impl Uint256 {
+    /// Quotient of a division the caller knows to be exact, without Knuth.
+    ///
+    /// Uses Jebelean's exact division: strip the common power of two, then
+    /// multiply by the inverse of the odd part of `d` mod 2^256. The inverse
+    /// starts from `3d ^ 2`, correct to 5 bits, and each Newton step
+    /// `x * (2 - d * x)` doubles that.
+    ///
+    /// If `d` does not divide `self` the result is meaningless; debug builds
+    /// assert exactness. Panics if `d` is zero.
+    pub fn exact_div(self, d: Self) -> Self {
+        if d.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        debug_assert!((self % d).is_zero(), "exact_div: divisor does not divide the dividend");
+
+        let tz = d.trailing_zeros();
+        let d = d >> tz;
+        let n = self >> tz;
+
+        let two = Self::from_u64(2);
+        let mut inv = d.mul_u64(3) ^ two;
+        // 5 -> 10 -> 20 -> 40 -> 80 -> 160 -> 320 bits
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(two.wrapping_sub(d.wrapping_mul(inv)));
+        }
+        n.wrapping_mul(inv)
+    }
+
+    /// Multiplication by a u64 scalar, wrapping at 256 bits.
+    #[inline]
+    pub fn mul_u64(self, d: u64) -> Self {
+        self.widening_mul_u64(d).0
+    }
+
+    /// Multiplication by a u64 scalar, or `None` on overflow.
+    #[inline]
+    pub fn checked_mul_u64(self, d: u64) -> Option<Self> {
+        match self.widening_mul_u64(d) {
+            (r, 0) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Multiplication by a u64 scalar returning (low 256 bits, carry limb).
+    /// A single pass from the least significant limb, one u128 product each.
+    #[inline]
+    pub fn widening_mul_u64(self, d: u64) -> (Self, u64) {
+        self.widening_mul_add_u64(d, 0)
+    }
+
+    /// `self * mul + add`, wrapping at 256 bits. One step of Horner's method.
+    #[inline]
+    pub fn mul_add_u64(self, mul: u64, add: u64) -> Self {
+        self.widening_mul_add_u64(mul, add).0
+    }
+
+    /// `self * mul + add`, or `None` if it does not fit in 256 bits.
+    #[inline]
+    pub fn checked_mul_add_u64(self, mul: u64, add: u64) -> Option<Self> {
+        match self.widening_mul_add_u64(mul, add) {
+            (r, 0) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// `self * mul + add` returning (low 256 bits, carry limb). The addend
+    /// enters as the initial carry of the `widening_mul_u64` pass; every
+    /// step is at most `(2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1`, so it
+    /// cannot overflow the u128.
+    #[inline]
+    pub fn widening_mul_add_u64(self, mul: u64, add: u64) -> (Self, u64) {
+        let t = self.l0 as u128 * mul as u128 + add as u128;
+        let l0 = t as u64;
+        let t = self.l1 as u128 * mul as u128 + (t >> 64);
+        let l1 = t as u64;
+        let t = self.l2 as u128 * mul as u128 + (t >> 64);
+        let l2 = t as u64;
+        let t = self.l3 as u128 * mul as u128 + (t >> 64);
+        let l3 = t as u64;
+
+        (Self { l0, l1, l2, l3 }, (t >> 64) as u64)
+    }
+
     /// Division by u64 using hardware div instruction.
     /// Computes quotient by processing limbs from most to least significant.
     #[inline]
     pub fn div_by_u64(self, d: u64) -> Self {
-        // r starts at 0, then accumulates remainder as we go
-        let (q3, r) = div_u128_by_u64(self.l3 as u128, d);
+        self.divrem_by_u64(d).0
+    }
+
+    /// Division by u64 returning (quotient, remainder).
+    /// The remainder is what is left over after the final limb, so both
+    /// come from a single pass.
+    ///
+    /// Panics if `d` is zero. The top limb goes through native u64 division,
+    /// whose own zero check raises the panic before the x86_64 `div` runs.
+    #[inline]
+    pub fn divrem_by_u64(self, d: u64) -> (Self, u64) {
+        // The top limb has no incoming remainder, so native division suffices
+        let (q3, r) = (self.l3 / d, self.l3 % d);
         let (q2, r) = div_u128_by_u64((r as u128) << 64 | self.l2 as u128, d);
         let (q1, r) = div_u128_by_u64((r as u128) << 64 | self.l1 as u128, d);
-        let (q0, _) = div_u128_by_u64((r as u128) << 64 | self.l0 as u128, d);
+        let (q0, r) = div_u128_by_u64((r as u128) << 64 | self.l0 as u128, d);
 
-        Self { l0: q0, l1: q1, l2: q2, l3: q3 }
+        (Self { l0: q0, l1: q1, l2: q2, l3: q3 }, r)
     }
 
-    /// Division by u128 - quotient fits in 128 bits when divisor > 2^64.
+    /// Remainder of division by a u64, without building the quotient.
+    /// The same limb walk as `divrem_by_u64`, carrying only the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is zero, from the native `%` on the top limb.
     #[inline]
-    fn div_by_u128(self, d: u128) -> Self {
+    pub fn rem_u64(self, d: u64) -> u64 {
+        [self.l2, self.l1, self.l0]
+            .into_iter()
+            .fold(self.l3 % d, |r, limb| div_u128_by_u64((r as u128) << 64 | limb as u128, d).1)
+    }
+
+    /// Division by u128 returning (quotient, remainder).
+    /// The low quotient half fits in 128 bits when divisor > 2^64.
+    #[inline(always)]
+    fn divrem_by_u128(self, d: u128) -> (Self, u128) {
         // Combine high and low halves for the divisions
         let n_hi = (self.l3 as u128) << 64 | self.l2 as u128;
-        let n_lo = (self.l1 as u128) << 64 | self.l0 as u128;
+        let n_lo = self.to_u128();
 
         // First: divide high 128 bits
         let (q_hi, r_hi) = (n_hi / d, n_hi % d);
@@ -267,24 +978,29 @@ impl Uint256 {
         // This requires 256÷128 which we approximate with Knuth-style estimation
         let q_lo = div_u256_by_u128(r_hi, n_lo, d);
 
-        Self {
+        // The true remainder is below d, so the low 128 bits of
+        // (r_hi:n_lo) - q_lo*d are all of it.
+        let r = n_lo.wrapping_sub(q_lo.wrapping_mul(d));
+
+        let q = Self {
             l0: q_lo as u64,
             l1: (q_lo >> 64) as u64,
             l2: q_hi as u64,
             l3: (q_hi >> 64) as u64,
-        }
+        };
+        (q, r)
     }
 
-    /// Knuth Algorithm D for full 256÷256 division.
+    /// Knuth Algorithm D for full 256÷256 division, returning (quotient, remainder).
     /// Used when divisor has bits in l2 or l3.
-    #[inline]
-    fn div_knuth(self, d: Self) -> Self {
+    #[inline(always)]
+    fn div_knuth(self, d: Self) -> (Self, Self) {
         // When divisor uses high limbs, quotient is small
         // If d.l3 != 0, quotient fits in ~64 bits
         // If d.l2 != 0, quotient fits in ~128 bits
 
         if self < d {
-            return Self::ZERO;
+            return (Self::ZERO, self);
         }
 
         if d.l3 != 0 {
@@ -297,8 +1013,8 @@ impl Uint256 {
     }
 
     /// 256÷256 where divisor uses l3; quotient fits in 64 bits.
-    #[inline]
-    fn div_large_divisor_64bit_quotient(self, d: Self) -> Self {
+    #[inline(always)]
+    fn div_large_divisor_64bit_quotient(self, d: Self) -> (Self, Self) {
         debug_assert!(d.l3 != 0);
 
         let shift = d.l3.leading_zeros();
@@ -339,12 +1055,29 @@ impl Uint256 {
             add_back_limbs(&mut rem, 0, &d_norm);
         }
 
-        Self { l0: qhat, l1: 0, l2: 0, l3: 0 }
+        let q = Self { l0: qhat, l1: 0, l2: 0, l3: 0 };
+        (q, denormalize(&rem, shift))
     }
 
     /// 256÷192 where divisor uses l2; quotient fits in 128 bits.
-    #[inline]
-    fn div_large_divisor_128bit_quotient(self, d: Self) -> Self {
+    ///
+    /// Invariants on `rem` (the normalized dividend, least significant limb
+    /// first):
+    /// - `rem[5]` and `rem[6]` are zero: a 256-bit value shifted by less
+    ///   than 64 bits spans at most five limbs.
+    /// - Before step `j`, every limb above `rem[j + 3]` is zero and the
+    ///   window `rem[j..=j + 3]` is below `d_norm * 2^64`, so the quotient
+    ///   digit fits in a u64.
+    /// - After step `j`, `rem[j..=j + 2]` holds a value below `d_norm` and
+    ///   `rem[j + 3]` is zero.
+    ///
+    /// The estimate from the top two divisor limbs, refined against
+    /// `d_norm.l1`, is never too small and at most one too large (Knuth,
+    /// TAOCP 4.3.1 Theorem B), so a single add-back always restores a
+    /// nonnegative window. `add_back_limbs_3` drops the carry out of
+    /// `rem[j + 3]`, which cancels the borrow `sub_mul_limbs_3` reported.
+    #[inline(always)]
+    fn div_large_divisor_128bit_quotient(self, d: Self) -> (Self, Self) {
         debug_assert!(d.l2 != 0 && d.l3 == 0);
 
         let shift = d.l2.leading_zeros();
@@ -396,7 +1129,113 @@ impl Uint256 {
             }
         }
 
-        Self { l0: q_lo, l1: q_hi, l2: 0, l3: 0 }
+        let q = Self { l0: q_lo, l1: q_hi, l2: 0, l3: 0 };
+        (q, denormalize(&rem, shift))
+    }
+
+    /// Integer square root, rounded down.
+    ///
+    /// Bit-by-bit method: tries each result bit from the top, so it needs
+    /// only shifts, adds and compares and never touches the division path.
+    pub fn isqrt(self) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+
+        let mut x = self;
+        let mut r = Self::ZERO;
+        // Highest power of four not exceeding self.
+        let mut bit = Self::ONE << ((255 - self.leading_zeros()) & !1);
+
+        while !bit.is_zero() {
+            let trial = r + bit;
+            if x >= trial {
+                x -= trial;
+                r = (r >> 1) + bit;
+            } else {
+                r >>= 1;
+            }
+            bit >>= 2;
+        }
+
+        r
+    }
+
+    /// Base-2 logarithm, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, like `u128::ilog2`.
+    pub fn ilog2(self) -> u32 {
+        if self.is_zero() {
+            panic!("argument of integer logarithm must be positive");
+        }
+        255 - self.leading_zeros()
+    }
+
+    /// Base-10 logarithm, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, like `u128::ilog10`.
+    pub fn ilog10(self) -> u32 {
+        self.ilog(Self::from_u64(10))
+    }
+
+    /// Logarithm in the given base, rounded down.
+    ///
+    /// Counts how many times `base` can be multiplied up before passing
+    /// `self`; stopping when the next power would overflow is what keeps
+    /// this correct near `MAX`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or `base` is less than 2, like `u128::ilog`.
+    pub fn ilog(self, base: Self) -> u32 {
+        if base < Self::from_u64(2) {
+            panic!("base of the integer logarithm must be at least 2");
+        }
+        if self.is_zero() {
+            panic!("argument of integer logarithm must be positive");
+        }
+
+        let mut n = 0;
+        let mut power = base;
+        while power <= self {
+            n += 1;
+            match power.checked_mul(base) {
+                Some(next) => power = next,
+                None => break,
+            }
+        }
+        n
+    }
+
+    /// Greatest common divisor by Stein's binary algorithm: only shifts,
+    /// compares and subtractions, no division. `gcd(x, 0) == x`.
+    pub fn gcd(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+
+        // Common factors of two, restored at the end
+        let shift = (self | other).trailing_zeros();
+        let mut a = self >> self.trailing_zeros();
+        let mut b = other;
+
+        // a stays odd; strip b's twos and subtract the smaller from the larger
+        while !b.is_zero() {
+            b >>= b.trailing_zeros();
+            if a > b {
+                std::mem::swap(&mut a, &mut b);
+            }
+            b -= a;
+        }
+
+        a << shift
     }
 
     /// Count leading zeros
@@ -413,6 +1252,170 @@ impl Uint256 {
         }
     }
 
+    /// Leading one bits. All-ones yields the full width (256).
+    pub fn leading_ones(&self) -> u32 {
+        (!*self).leading_zeros()
+    }
+
+    /// Shift left until the top bit is set, returning the shifted value and
+    /// the shift, which is `leading_zeros()`. This is the normalization
+    /// long division applies to a divisor so quotient digit estimates stay
+    /// within two of the truth. Zero returns `(0, 256)`.
+    pub fn normalize(self) -> (Self, u32) {
+        let shift = self.leading_zeros();
+        (self << shift, shift)
+    }
+
+    /// Minimal number of bits needed to represent the value: 0 for zero,
+    /// otherwise `256 - leading_zeros()`.
+    pub fn bits(&self) -> u32 {
+        256 - self.leading_zeros()
+    }
+
+    /// Reverse the byte order: the limb order is reversed and each limb is
+    /// byte-swapped, as `u128::swap_bytes` does for two limbs.
+    pub const fn swap_bytes(self) -> Self {
+        Self {
+            l0: self.l3.swap_bytes(),
+            l1: self.l2.swap_bytes(),
+            l2: self.l1.swap_bytes(),
+            l3: self.l0.swap_bytes(),
+        }
+    }
+
+    /// Reverse the bit order, so bit 0 becomes bit 255.
+    pub const fn reverse_bits(self) -> Self {
+        Self {
+            l0: self.l3.reverse_bits(),
+            l1: self.l2.reverse_bits(),
+            l2: self.l1.reverse_bits(),
+            l3: self.l0.reverse_bits(),
+        }
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.l0.count_ones() + self.l1.count_ones() + self.l2.count_ones() + self.l3.count_ones()
+    }
+
+    /// Number of clear bits.
+    pub fn count_zeros(&self) -> u32 {
+        256 - self.count_ones()
+    }
+
+    /// Trailing zero bits. Zero yields the full width (256).
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.l0 != 0 {
+            self.l0.trailing_zeros()
+        } else if self.l1 != 0 {
+            64 + self.l1.trailing_zeros()
+        } else if self.l2 != 0 {
+            128 + self.l2.trailing_zeros()
+        } else {
+            192 + self.l3.trailing_zeros()
+        }
+    }
+
+    /// Trailing one bits. All-ones yields the full width (256).
+    pub fn trailing_ones(&self) -> u32 {
+        if self.l0 != u64::MAX {
+            self.l0.trailing_ones()
+        } else if self.l1 != u64::MAX {
+            64 + self.l1.trailing_ones()
+        } else if self.l2 != u64::MAX {
+            128 + self.l2.trailing_ones()
+        } else {
+            192 + self.l3.trailing_ones()
+        }
+    }
+
+    /// True if exactly one bit is set.
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Smallest power of two greater than or equal to `self`.
+    ///
+    /// Wraps to `ZERO` when that power exceeds `MAX` (like native
+    /// `next_power_of_two` in release builds); `ZERO` rounds up to `ONE`.
+    pub fn next_power_of_two(self) -> Self {
+        if self <= Self::ONE {
+            return Self::ONE;
+        }
+        let p = 256 - (self - Self::ONE).leading_zeros();
+        if p == 256 { Self::ZERO } else { Self::ONE << p }
+    }
+
+    /// Whether bit `i` is set, counting from the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= 256`, like the other single-bit accessors.
+    pub fn bit(&self, i: u32) -> bool {
+        let limb = match i / 64 {
+            0 => self.l0,
+            1 => self.l1,
+            2 => self.l2,
+            3 => self.l3,
+            _ => panic!("bit index {i} out of range for Uint256"),
+        };
+        limb >> (i % 64) & 1 == 1
+    }
+
+    /// Set bit `i` to 1. Panics if `i >= 256`.
+    pub fn set_bit(&mut self, i: u32) {
+        *self.limb_mut(i) |= 1 << (i % 64);
+    }
+
+    /// Set bit `i` to 0. Panics if `i >= 256`.
+    pub fn clear_bit(&mut self, i: u32) {
+        *self.limb_mut(i) &= !(1 << (i % 64));
+    }
+
+    /// Toggle bit `i`. Panics if `i >= 256`.
+    pub fn flip_bit(&mut self, i: u32) {
+        *self.limb_mut(i) ^= 1 << (i % 64);
+    }
+
+    /// Indices of the set bits, from low to high.
+    ///
+    /// Walks one limb at a time, taking `trailing_zeros` and clearing the
+    /// lowest set bit, so the cost is proportional to the number of ones.
+    pub fn iter_ones(&self) -> impl Iterator<Item = u32> + use<> {
+        let limbs = [self.l0, self.l1, self.l2, self.l3];
+        let mut idx = 0;
+        let mut word = limbs[0];
+        std::iter::from_fn(move || {
+            while word == 0 {
+                idx += 1;
+                if idx == limbs.len() {
+                    return None;
+                }
+                word = limbs[idx];
+            }
+            let bit = word.trailing_zeros();
+            word &= word - 1;
+            Some(idx as u32 * 64 + bit)
+        })
+    }
+
+    /// All 256 bits as booleans, from bit 0 up.
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> + use<> {
+        let v = *self;
+        (0..256).map(move |i| v.bit(i))
+    }
+
+    /// The limb holding bit `i`.
+    fn limb_mut(&mut self, i: u32) -> &mut u64 {
+        match i / 64 {
+            0 => &mut self.l0,
+            1 => &mut self.l1,
+            2 => &mut self.l2,
+            3 => &mut self.l3,
+            _ => panic!("bit index {i} out of range for Uint256"),
+        }
+    }
+
     /// Shift left, returning 448 bits (7 limbs) to capture overflow.
     /// The extra limbs capture overflow from the shift and are needed for Knuth division
     /// to safely access indices during quotient digit estimation.
@@ -465,9 +1468,7 @@ impl Uint256 {
         let limbs = [self.l0, self.l1, self.l2, self.l3];
 
         if bits == 0 {
-            for i in full_limbs..4 {
-                result[i] = limbs[i - full_limbs];
-            }
+            result[full_limbs..].copy_from_slice(&limbs[..4 - full_limbs]);
         } else {
             for i in full_limbs..4 {
                 result[i] = limbs[i - full_limbs] << bits;
@@ -486,6 +1487,490 @@ impl Uint256 {
     }
 }
 
+// ============================================================================
+// Modular arithmetic
+// ============================================================================
+
+impl Uint256 {
+    /// `(self * rhs) mod modulus`, reducing the full 512-bit product so no
+    /// bits are lost.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn mulmod(self, rhs: Self, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+        rem_u512_by_u256(self.mul_wide(rhs), modulus)
+    }
+
+    /// `(self + rhs) mod modulus`. The carry out of the addition becomes a
+    /// 257th bit, which the reduction takes into account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn addmod(self, rhs: Self, modulus: Self) -> Self {
+        if modulus.is_zero() {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+        let (sum, carry) = self.overflowing_add(rhs);
+        rem_u512_by_u256([sum.l0, sum.l1, sum.l2, sum.l3, carry as u64, 0, 0, 0], modulus)
+    }
+
+    /// `(self - rhs) mod modulus`, always in `[0, modulus)`. Both operands
+    /// are reduced first; a borrow is fixed by adding `modulus` back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn submod(self, rhs: Self, modulus: Self) -> Self {
+        let a = self % modulus;
+        let b = rhs % modulus;
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow { diff + modulus } else { diff }
+    }
+
+    /// `self^exp mod modulus` by left-to-right square-and-multiply.
+    ///
+    /// `powmod(a, 0, m)` is `1 % m`, so it is zero when `m == 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn powmod(self, exp: Self, modulus: Self) -> Self {
+        let mut result = Self::ONE % modulus;
+        let base = self % modulus;
+
+        for i in (0..256 - exp.leading_zeros()).rev() {
+            result = result.mulmod(result, modulus);
+            if (exp >> i).l0 & 1 == 1 {
+                result = result.mulmod(base, modulus);
+            }
+        }
+
+        result
+    }
+}
+
+// ============================================================================
+// Constant-time operations
+// ============================================================================
+
+impl Uint256 {
+    /// Constant-time equality: 1 if equal, 0 otherwise.
+    ///
+    /// ORs the limb XORs together and folds the result to a single bit with
+    /// no early exit, so the running time does not depend on where the
+    /// values differ. `PartialEq` and `Ord` stop at the first differing limb
+    /// and must not be used on secrets.
+    pub fn ct_eq(&self, other: &Self) -> u8 {
+        let diff = (self.l0 ^ other.l0)
+            | (self.l1 ^ other.l1)
+            | (self.l2 ^ other.l2)
+            | (self.l3 ^ other.l3);
+        // diff | -diff has its top bit set exactly when diff != 0
+        (((diff | diff.wrapping_neg()) >> 63) as u8) ^ 1
+    }
+
+    /// Branch-free select: `a` if `choice` is 1, `b` if it is 0.
+    ///
+    /// `choice` must be 0 or 1, as returned by `ct_eq`. It is widened to an
+    /// all-ones or all-zeros mask that blends the limbs.
+    pub fn ct_select(choice: u8, a: Self, b: Self) -> Self {
+        let mask = ((choice & 1) as u64).wrapping_neg();
+        Self {
+            l0: b.l0 ^ (mask & (a.l0 ^ b.l0)),
+            l1: b.l1 ^ (mask & (a.l1 ^ b.l1)),
+            l2: b.l2 ^ (mask & (a.l2 ^ b.l2)),
+            l3: b.l3 ^ (mask & (a.l3 ^ b.l3)),
+        }
+    }
+}
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+impl std::fmt::Display for Uint256 {
+    /// Decimal formatting.
+    ///
+    /// Peels off 19 digits at a time by dividing by 10^19, the largest power
+    /// of ten that fits in a u64, then prints the chunks most significant
+    /// first. 2^256 < 10^78, so at most five chunks are needed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        const TEN_POW_19: u64 = 10_000_000_000_000_000_000;
+
+        let mut chunks = [0u64; 5];
+        let mut len = 0;
+        let mut v = *self;
+        loop {
+            let (q, r) = v.divrem_by_u64(TEN_POW_19);
+            chunks[len] = r;
+            len += 1;
+            v = q;
+            if v.is_zero() {
+                break;
+            }
+        }
+
+        let mut buf = String::with_capacity(78);
+        write!(buf, "{}", chunks[len - 1])?;
+        for chunk in chunks[..len - 1].iter().rev() {
+            write!(buf, "{chunk:019}")?;
+        }
+        f.pad_integral(true, "", &buf)
+    }
+}
+
+impl std::fmt::LowerHex for Uint256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_pow2_radix(self, 4, false, "0x", f)
+    }
+}
+
+impl std::fmt::UpperHex for Uint256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_pow2_radix(self, 4, true, "0x", f)
+    }
+}
+
+impl std::fmt::Binary for Uint256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_pow2_radix(self, 1, false, "0b", f)
+    }
+}
+
+impl std::fmt::Octal for Uint256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_pow2_radix(self, 3, false, "0o", f)
+    }
+}
+
+/// Format in a power-of-two radix with `bits` bits per digit (1, 3 or 4).
+///
+/// Digits are read straight out of the limbs from least significant up, so
+/// zeros inside a limb are kept and no division is needed. An octal digit can
+/// straddle two limbs, in which case its high bits come from the next limb.
+/// The `#` prefix and width/fill flags are handled by `pad_integral`.
+fn fmt_pow2_radix(
+    v: &Uint256,
+    bits: u32,
+    upper: bool,
+    prefix: &str,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    const LOWER: &[u8; 16] = b"0123456789abcdef";
+    const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+    let limbs = [v.l0, v.l1, v.l2, v.l3];
+    let significant = 256 - v.leading_zeros();
+    let num_digits = significant.div_ceil(bits).max(1) as usize;
+    let mask = (1u64 << bits) - 1;
+    let table = if upper { UPPER } else { LOWER };
+
+    let mut buf = [0u8; 256];
+    for i in 0..num_digits {
+        let bit = i as u32 * bits;
+        let limb = (bit / 64) as usize;
+        let offset = bit % 64;
+        let mut digit = limbs[limb] >> offset;
+        if offset + bits > 64 && limb + 1 < 4 {
+            digit |= limbs[limb + 1] << (64 - offset);
+        }
+        buf[num_digits - 1 - i] = table[(digit & mask) as usize];
+    }
+
+    // Only ASCII digits were written
+    let digits = std::str::from_utf8(&buf[..num_digits]).unwrap();
+    f.pad_integral(true, prefix, digits)
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+impl Uint256 {
+    /// Parse a string in the given radix (2..=36), like `u128::from_str_radix`.
+    ///
+    /// Accepts an optional leading `+`, then an optional prefix matching the
+    /// radix: `0x` for 16, `0o` for 8, `0b` for 2. Other prefixes are not
+    /// stripped, so `"0b1"` in radix 16 parses as `0xb1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in 2..=36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix_int: must lie in the range `[2, 36]` - found {radix}"
+        );
+
+        if s.is_empty() {
+            return Err(ParseIntError::new(IntErrorKind::Empty));
+        }
+        Self::parse_digits(s.strip_prefix('+').unwrap_or(s), radix)
+    }
+
+    /// Parse an unsigned magnitude once any sign has been removed.
+    /// Strips the radix prefix, then accumulates `acc * radix + digit`.
+    pub(crate) fn parse_digits(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+        let digits = strip_radix_prefix(s, radix);
+        if digits.is_empty() {
+            return Err(ParseIntError::new(IntErrorKind::InvalidDigit));
+        }
+
+        let overflow = ParseIntError::new(IntErrorKind::PosOverflow);
+        let base = Self::from_u64(radix as u64);
+        let mut acc = Self::ZERO;
+        for c in digits.chars() {
+            let digit = c
+                .to_digit(radix)
+                .ok_or(ParseIntError::new(IntErrorKind::InvalidDigit))?;
+            acc = acc
+                .checked_mul(base)
+                .and_then(|v| v.checked_add(Self::from_u64(digit as u64)))
+                .ok_or(overflow.clone())?;
+        }
+        Ok(acc)
+    }
+
+    /// Digits in the given radix (2..=256), least significant first.
+    ///
+    /// Each digit is the remainder of one `divrem_by_u64` pass. Zero yields
+    /// a single `0` digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in 2..=256.
+    pub fn to_radix_le(self, radix: u32) -> Vec<u8> {
+        assert!(
+            (2..=256).contains(&radix),
+            "to_radix_le: radix must lie in the range `[2, 256]` - found {radix}"
+        );
+
+        let mut digits = Vec::new();
+        let mut n = self;
+        loop {
+            let (q, r) = n.divrem_by_u64(radix as u64);
+            digits.push(r as u8);
+            n = q;
+            if n.is_zero() {
+                return digits;
+            }
+        }
+    }
+
+    /// Inverse of `to_radix_le`: rebuild a value from digit values in the
+    /// given radix (2..=256), least significant first. An empty slice is
+    /// zero.
+    ///
+    /// Returns `None` if any digit is `>= radix` or the value does not fit
+    /// in 256 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in 2..=256.
+    pub fn from_radix_le(digits: &[u8], radix: u32) -> Option<Self> {
+        assert!(
+            (2..=256).contains(&radix),
+            "from_radix_le: radix must lie in the range `[2, 256]` - found {radix}"
+        );
+
+        let mut acc = Self::ZERO;
+        for &d in digits.iter().rev() {
+            if d as u32 >= radix {
+                return None;
+            }
+            let (scaled, carry) = acc.widening_mul_u64(radix as u64);
+            if carry != 0 {
+                return None;
+            }
+            acc = scaled.checked_add(Self::from_u64(d as u64))?;
+        }
+        Some(acc)
+    }
+
+    const fn from_u64(v: u64) -> Self {
+        Self {
+            l0: v,
+            l1: 0,
+            l2: 0,
+            l3: 0,
+        }
+    }
+}
+
+impl std::str::FromStr for Uint256 {
+    type Err = ParseIntError;
+
+    /// Parse a decimal string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+/// Strip the `0x`/`0o`/`0b` prefix that matches `radix`, if present.
+fn strip_radix_prefix(s: &str, radix: u32) -> &str {
+    let prefix = match radix {
+        16 => ["0x", "0X"],
+        8 => ["0o", "0O"],
+        2 => ["0b", "0B"],
+        _ => return s,
+    };
+    prefix.iter().find_map(|p| s.strip_prefix(p)).unwrap_or(s)
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<u128> for Uint256 {
+    fn from(v: u128) -> Self {
+        Self::from_u128(v)
+    }
+}
+
+impl From<u64> for Uint256 {
+    fn from(v: u64) -> Self {
+        Self::from_u64(v)
+    }
+}
+
+impl From<u32> for Uint256 {
+    fn from(v: u32) -> Self {
+        Self::from_u64(v as u64)
+    }
+}
+
+impl From<u8> for Uint256 {
+    fn from(v: u8) -> Self {
+        Self::from_u64(v as u64)
+    }
+}
+
+impl From<usize> for Uint256 {
+    fn from(v: usize) -> Self {
+        Self::from_u128(v as u128)
+    }
+}
+
+/// Fails if the value exceeds `usize::MAX` on this target.
+impl TryFrom<Uint256> for usize {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint256) -> Result<Self, Self::Error> {
+        v.try_to_u128()
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or(TryFromIntError(()))
+    }
+}
+
+/// Zero-extends into the low limb.
+impl From<Uint64> for Uint256 {
+    fn from(v: Uint64) -> Self {
+        Self::from_u64(v.to_u64())
+    }
+}
+
+/// Zero-extends into the low two limbs.
+impl From<Uint128> for Uint256 {
+    fn from(v: Uint128) -> Self {
+        Self { l0: v.l, l1: v.h, l2: 0, l3: 0 }
+    }
+}
+
+/// Fails unless the upper two limbs are zero.
+impl TryFrom<Uint256> for Uint128 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint256) -> Result<Self, Self::Error> {
+        if v.l2 == 0 && v.l3 == 0 {
+            Ok(Uint128 { l: v.l0, h: v.l1 })
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+/// Fails unless the upper three limbs are zero.
+impl TryFrom<Uint256> for Uint64 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint256) -> Result<Self, Self::Error> {
+        if v.l1 == 0 && v.l2 == 0 && v.l3 == 0 {
+            Ok(Uint64::from_u64(v.l0))
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+/// Reads the slice as a big-endian unsigned integer. Slices shorter than 32
+/// bytes are zero-extended; longer ones are accepted only if every byte
+/// beyond the low 32 is zero.
+impl TryFrom<&[u8]> for Uint256 {
+    type Error = TryFromIntError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let (excess, bytes) = bytes.split_at(bytes.len().saturating_sub(32));
+        if excess.iter().any(|&b| b != 0) {
+            return Err(TryFromIntError(()));
+        }
+        Self::from_be_slice(bytes).map_err(|_| TryFromIntError(()))
+    }
+}
+
+impl Uint256 {
+    /// Convert from f64, truncating any fractional part toward zero.
+    ///
+    /// Returns `None` for NaN, negative values, and values `>= 2^256`
+    /// (including infinity). The 53-bit significand is placed directly from
+    /// the IEEE 754 fields, so every finite in-range input converts exactly.
+    pub fn from_f64(v: f64) -> Option<Self> {
+        if v.is_nan() || v < 0.0 {
+            return None;
+        }
+        let bits = v.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        // 2^256 has biased exponent 1023 + 256; infinity is above it.
+        if biased >= 1023 + 256 {
+            return None;
+        }
+        // Zero, subnormals and anything below 1.0 truncate to zero.
+        if biased < 1023 {
+            return Some(Self::ZERO);
+        }
+        let mantissa = (bits & ((1 << 52) - 1)) | 1 << 52;
+        // v == mantissa * 2^exp
+        let exp = biased - 1075;
+        Some(if exp < 0 {
+            Self::from_u64(mantissa >> -exp)
+        } else {
+            Self::from_u64(mantissa) << exp as u32
+        })
+    }
+
+    /// Convert to the nearest f64, ties to even, like `u128 as f64`.
+    ///
+    /// Values above 64 bits keep their top 64 bits with a sticky bit for
+    /// anything shifted out, let the native u64 conversion do the rounding,
+    /// then scale by the shift, which is exact for a power of two.
+    pub fn to_f64(self) -> f64 {
+        let n = self.bits();
+        if n <= 64 {
+            return self.l0 as f64;
+        }
+        let shift = n - 64;
+        let sticky = (self << (256 - shift)) != Self::ZERO;
+        let top = (self >> shift).l0 | sticky as u64;
+        top as f64 * f64::from_bits(((1023 + shift) as u64) << 52)
+    }
+}
+
 // ============================================================================
 // Division helper functions
 // ============================================================================
@@ -535,9 +2020,13 @@ fn div_u256_by_u128(hi: u128, lo: u128, d: u128) -> u128 {
     let d_norm = d << shift;
     let d_hi = (d_norm >> 64) as u64;
 
-    // Shift numerator
-    let n2 = (hi << shift) | (lo >> (128 - shift));
-    let n1 = lo << shift;
+    // Shift numerator. A divisor with its top bit already set needs no
+    // normalization, and `lo >> 128` would overflow.
+    let (n2, n1) = if shift == 0 {
+        (hi, lo)
+    } else {
+        ((hi << shift) | (lo >> (128 - shift)), lo << shift)
+    };
 
     // Estimate high 64 bits of quotient
     let n_hi = (n2 >> 64) as u64;
@@ -594,6 +2083,13 @@ fn div_u256_by_u128(hi: u128, lo: u128, d: u128) -> u128 {
     (q_hi << 64) | qhat2 as u128
 }
 
+/// Undo Knuth normalization: after the last step the remainder is below the
+/// normalized divisor, so it sits in the low four limbs of `rem`.
+#[inline]
+fn denormalize(rem: &[u64; 7], shift: u32) -> Uint256 {
+    Uint256 { l0: rem[0], l1: rem[1], l2: rem[2], l3: rem[3] } >> shift
+}
+
 /// Subtract qhat * d from rem[j..j+5], returning true if borrow occurred.
 #[inline]
 fn sub_mul_limbs(rem: &mut [u64; 7], j: usize, d: &Uint256, qhat: u64) -> bool {
@@ -682,15 +2178,45 @@ fn add_back_limbs_3(rem: &mut [u64; 7], j: usize, d: &Uint256) {
     }
 }
 
+/// Remainder of a 512-bit value (eight limbs, least significant first) by a
+/// nonzero 256-bit divisor, via the limb-slice Knuth routine shared with
+/// `Uint512`.
+fn rem_u512_by_u256(n: [u64; 8], d: Uint256) -> Uint256 {
+    let mut q = [0u64; 8];
+    let mut r = [0u64; 4];
+    divrem_limbs(&n, &[d.l0, d.l1, d.l2, d.l3], &mut q, &mut r);
+    Uint256 { l0: r[0], l1: r[1], l2: r[2], l3: r[3] }
+}
+
 // ============================================================================
 // Optimal inline assembly implementations
 // ============================================================================
 
 /// Optimal u256 multiplication using inline assembly with BMI2.
-/// Carefully scheduled to minimize register pressure and spills.
+///
+/// Safe to call on any x86_64 CPU: BMI2 is detected at runtime (std caches
+/// the CPUID result), and without it this falls back to the same u128 path
+/// `Uint256::mul` uses. Building with `-C target-feature=+bmi2` turns the
+/// check into a constant.
 #[inline(never)]
 #[cfg(target_arch = "x86_64")]
 pub fn optimal_u256_mul(a: &Uint256, b: &Uint256) -> Uint256 {
+    if std::arch::is_x86_feature_detected!("bmi2") {
+        // SAFETY: BMI2 support was just detected.
+        unsafe { optimal_u256_mul_bmi2(a, b) }
+    } else {
+        a.wrapping_mul(*b)
+    }
+}
+
+/// The `mulx` kernel behind `optimal_u256_mul`.
+/// Carefully scheduled to minimize register pressure and spills.
+///
+/// Callers must ensure the CPU supports BMI2.
+#[inline(never)]
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+pub(crate) unsafe fn optimal_u256_mul_bmi2(a: &Uint256, b: &Uint256) -> Uint256 {
     let mut r0: u64;
     let mut r1: u64;
     let mut r2: u64;
@@ -733,8 +2259,7 @@ pub fn optimal_u256_mul(a: &Uint256, b: &Uint256) -> Uint256 {
             "mov rdx, [{b} + 16]",              // rdx = b2
             "mulx {t3}, {t0}, [{a}]",           // t3:t0 = a0*b2
             "add {t2}, {t0}",                   // t2 += lo(a0*b2), t2 is now r2
-            "adc {t4}, {t3}",                   // t4 += hi(a0*b2) + carry
-            "setc {t3:l}",                      // save final carry
+            "adc {t4}, {t3}",                   // t4 += hi(a0*b2) + carry, carry out is bit 256
 
             "mov {r2}, {t2}",                   // save r2
 
@@ -755,8 +2280,6 @@ pub fn optimal_u256_mul(a: &Uint256, b: &Uint256) -> Uint256 {
             "imul rdx, [{a} + 24]",             // a3*b0
             "add {t4}, rdx",
 
-            "movzx {t3:e}, {t3:l}",             // zero-extend carry
-            "add {t4}, {t3}",                   // add final carry
             "mov {r3}, {t4}",
 
             a = in(reg) a as *const Uint256 as *const u64,
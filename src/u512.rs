@@ -0,0 +1,513 @@
+use crate::biguint::BigUint;
+use crate::error::TryFromIntError;
+use crate::u256::Uint256;
+use std::cmp::Ordering;
+
+/// 512-bit unsigned integer stored as eight 64-bit limbs.
+///
+/// Field order matches native memory layout, as for [`Uint256`]:
+/// - Little-endian: { l0, ..., l7 } - LSB at lower address
+/// - Big-endian: { l7, ..., l0 } - MSB at lower address
+///
+/// Arithmetic loops over the limbs rather than unrolling them; the main use
+/// is holding full products of 256-bit values and reducing them.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_endian = "little")]
+pub struct Uint512 {
+    pub l0: u64, // bits 0-63 (lowest address)
+    pub l1: u64, // bits 64-127
+    pub l2: u64, // bits 128-191
+    pub l3: u64, // bits 192-255
+    pub l4: u64, // bits 256-319
+    pub l5: u64, // bits 320-383
+    pub l6: u64, // bits 384-447
+    pub l7: u64, // bits 448-511 (highest address)
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_endian = "big")]
+pub struct Uint512 {
+    pub l7: u64, // bits 448-511 (lowest address)
+    pub l6: u64, // bits 384-447
+    pub l5: u64, // bits 320-383
+    pub l4: u64, // bits 256-319
+    pub l3: u64, // bits 192-255
+    pub l2: u64, // bits 128-191
+    pub l1: u64, // bits 64-127
+    pub l0: u64, // bits 0-63 (highest address)
+}
+
+impl Uint512 {
+    pub const ZERO: Self = Self::from_array([0; 8]);
+    pub const ONE: Self = Self::from_array([1, 0, 0, 0, 0, 0, 0, 0]);
+    pub const MIN: Self = Self::ZERO;
+    pub const MAX: Self = Self::from_array([u64::MAX; 8]);
+
+    /// Concatenate two 256-bit halves, as returned by
+    /// [`Uint256::widening_mul`].
+    pub const fn from_halves(hi: Uint256, lo: Uint256) -> Self {
+        Self::from_array([lo.l0, lo.l1, lo.l2, lo.l3, hi.l0, hi.l1, hi.l2, hi.l3])
+    }
+
+    /// Split into `(high, low)` 256-bit halves.
+    pub const fn split(self) -> (Uint256, Uint256) {
+        let hi = Uint256 {
+            l0: self.l4,
+            l1: self.l5,
+            l2: self.l6,
+            l3: self.l7,
+        };
+        let lo = Uint256 {
+            l0: self.l0,
+            l1: self.l1,
+            l2: self.l2,
+            l3: self.l3,
+        };
+        (hi, lo)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.to_array().iter().all(|&l| l == 0)
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        let limbs = self.to_array();
+        match limbs.iter().rposition(|&l| l != 0) {
+            Some(i) => (7 - i as u32) * 64 + limbs[i].leading_zeros(),
+            None => 512,
+        }
+    }
+
+    /// Limbs least significant first.
+    pub(crate) const fn to_array(self) -> [u64; 8] {
+        [
+            self.l0, self.l1, self.l2, self.l3, self.l4, self.l5, self.l6, self.l7,
+        ]
+    }
+
+    pub(crate) const fn from_array(l: [u64; 8]) -> Self {
+        Self {
+            l0: l[0],
+            l1: l[1],
+            l2: l[2],
+            l3: l[3],
+            l4: l[4],
+            l5: l[5],
+            l6: l[6],
+            l7: l[7],
+        }
+    }
+}
+
+impl Default for Uint512 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl std::ops::Add for Uint512 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.overflowing_add(rhs).0
+    }
+}
+
+impl std::ops::Sub for Uint512 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl std::ops::Mul for Uint512 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.overflowing_mul(rhs).0
+    }
+}
+
+impl Uint512 {
+    /// Addition returning the wrapped result and the carry out of `l7`.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (r, carry) = BigUint::from(self).overflowing_add(rhs.into());
+        (r.into(), carry)
+    }
+
+    /// Subtraction returning the wrapped result and the borrow out of `l7`.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (r, borrow) = BigUint::from(self).overflowing_sub(rhs.into());
+        (r.into(), borrow)
+    }
+
+    /// Multiplication returning the low 512 bits and whether any product bit
+    /// landed above bit 511. Schoolbook via [`BigUint::overflowing_mul`].
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (r, overflow) = BigUint::from(self).overflowing_mul(rhs.into());
+        (r.into(), overflow)
+    }
+
+    /// Checked addition. Returns `None` on carry out of `l7`.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on borrow out of `l7`.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` if any product bit lands
+    /// above bit 511.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(rhs).0)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(rhs).1)
+    }
+
+    /// Quotient and remainder from a single Knuth Algorithm D pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let mut q = [0u64; 8];
+        let mut r = [0u64; 8];
+        divrem_limbs(&self.to_array(), &rhs.to_array(), &mut q, &mut r);
+        (Self::from_array(q), Self::from_array(r))
+    }
+}
+
+impl PartialEq for Uint512 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl Eq for Uint512 {}
+
+impl PartialOrd for Uint512 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uint512 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_array()
+            .iter()
+            .rev()
+            .cmp(other.to_array().iter().rev())
+    }
+}
+
+impl std::ops::Not for Uint512 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::from_array(self.to_array().map(|l| !l))
+    }
+}
+
+impl std::ops::BitAnd for Uint512 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::from_array(std::array::from_fn(|i| a[i] & b[i]))
+    }
+}
+
+impl std::ops::BitOr for Uint512 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::from_array(std::array::from_fn(|i| a[i] | b[i]))
+    }
+}
+
+impl std::ops::BitXor for Uint512 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        Self::from_array(std::array::from_fn(|i| a[i] ^ b[i]))
+    }
+}
+
+impl std::ops::Shl<u32> for Uint512 {
+    type Output = Self;
+
+    fn shl(self, n: u32) -> Self::Output {
+        if n >= 512 {
+            return Self::ZERO;
+        }
+
+        let full_limbs = (n / 64) as usize;
+        let bits = n % 64;
+        let limbs = self.to_array();
+        let mut result = [0u64; 8];
+
+        for i in full_limbs..8 {
+            // Two adjacent source limbs as one u128; the shift is well
+            // defined for bits == 0.
+            let below = if i > full_limbs {
+                limbs[i - full_limbs - 1]
+            } else {
+                0
+            };
+            let pair = (limbs[i - full_limbs] as u128) << 64 | below as u128;
+            result[i] = ((pair << bits) >> 64) as u64;
+        }
+
+        Self::from_array(result)
+    }
+}
+
+impl std::ops::Shr<u32> for Uint512 {
+    type Output = Self;
+
+    /// Logical right shift: fills with zeros.
+    fn shr(self, n: u32) -> Self::Output {
+        if n >= 512 {
+            return Self::ZERO;
+        }
+
+        let full_limbs = (n / 64) as usize;
+        let bits = n % 64;
+        let limbs = self.to_array();
+        let mut result = [0u64; 8];
+
+        for i in 0..(8 - full_limbs) {
+            let above = if i + full_limbs + 1 < 8 {
+                limbs[i + full_limbs + 1]
+            } else {
+                0
+            };
+            let pair = (above as u128) << 64 | limbs[i + full_limbs] as u128;
+            result[i] = (pair >> bits) as u64;
+        }
+
+        Self::from_array(result)
+    }
+}
+
+forward_bit_assign_ops!(Uint512);
+shift_methods!(Uint512, 512);
+
+impl std::ops::Div for Uint512 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl std::ops::Rem for Uint512 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("attempt to calculate the remainder with a divisor of zero");
+        }
+        self.div_rem(rhs).1
+    }
+}
+
+forward_assign_ops!(Uint512);
+forward_ref_binops!(Uint512);
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+impl std::fmt::Display for Uint512 {
+    /// Decimal formatting in 19-digit chunks, as for `Uint256`.
+    /// 2^512 < 10^155, so at most nine chunks are needed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        const TEN_POW_19: u64 = 10_000_000_000_000_000_000;
+
+        let mut chunks = [0u64; 9];
+        let mut len = 0;
+        let mut v = self.to_array();
+        loop {
+            let mut r = 0u64;
+            for limb in v.iter_mut().rev() {
+                let n = (r as u128) << 64 | *limb as u128;
+                *limb = (n / TEN_POW_19 as u128) as u64;
+                r = (n % TEN_POW_19 as u128) as u64;
+            }
+            chunks[len] = r;
+            len += 1;
+            if v.iter().all(|&l| l == 0) {
+                break;
+            }
+        }
+
+        let mut buf = String::with_capacity(155);
+        write!(buf, "{}", chunks[len - 1])?;
+        for chunk in chunks[..len - 1].iter().rev() {
+            write!(buf, "{chunk:019}")?;
+        }
+        f.pad_integral(true, "", &buf)
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<u128> for Uint512 {
+    fn from(v: u128) -> Self {
+        Self::from(Uint256::from_u128(v))
+    }
+}
+
+impl From<u64> for Uint512 {
+    fn from(v: u64) -> Self {
+        Self::from(v as u128)
+    }
+}
+
+impl From<Uint256> for Uint512 {
+    fn from(v: Uint256) -> Self {
+        Self::from_halves(Uint256::ZERO, v)
+    }
+}
+
+/// Fails if any of the upper four limbs is nonzero.
+impl TryFrom<Uint512> for Uint256 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint512) -> Result<Self, Self::Error> {
+        match v.split() {
+            (hi, lo) if hi.is_zero() => Ok(lo),
+            _ => Err(TryFromIntError(())),
+        }
+    }
+}
+
+// ============================================================================
+// Division helper functions
+// ============================================================================
+
+/// Largest dividend, in limbs, that `divrem_limbs` accepts.
+const MAX_LIMBS: usize = 16;
+
+/// Knuth Algorithm D over little-endian limb slices.
+///
+/// Writes `n / d` into `q` and `n % d` into `r`, zeroing both first. `d`
+/// must be nonzero; its high zero limbs are ignored. `q` must hold at least
+/// `n.len()` limbs and `r` at least as many limbs as `d` has significant
+/// ones. `(x as u128) << s >> 64` extracts the bits shifted out of a limb
+/// and is well defined for `s == 0`.
+pub(crate) fn divrem_limbs(n: &[u64], d: &[u64], q: &mut [u64], r: &mut [u64]) {
+    let dl = d.len() - d.iter().rev().take_while(|&&x| x == 0).count();
+    let m = n.len();
+    debug_assert!(dl > 0 && m <= MAX_LIMBS);
+
+    q.fill(0);
+    r.fill(0);
+    if m < dl {
+        r[..m].copy_from_slice(n);
+        return;
+    }
+
+    if dl == 1 {
+        let mut rem = 0u64;
+        for i in (0..m).rev() {
+            let cur = (rem as u128) << 64 | n[i] as u128;
+            q[i] = (cur / d[0] as u128) as u64;
+            rem = (cur % d[0] as u128) as u64;
+        }
+        r[0] = rem;
+        return;
+    }
+
+    // Normalize so the divisor's top limb has its MSB set
+    let s = d[dl - 1].leading_zeros();
+    let spill = |x: u64| ((x as u128) << s >> 64) as u64;
+
+    let mut vn = [0u64; MAX_LIMBS];
+    for i in (1..dl).rev() {
+        vn[i] = d[i] << s | spill(d[i - 1]);
+    }
+    vn[0] = d[0] << s;
+
+    let mut un = [0u64; MAX_LIMBS + 1];
+    un[m] = spill(n[m - 1]);
+    for i in (1..m).rev() {
+        un[i] = n[i] << s | spill(n[i - 1]);
+    }
+    un[0] = n[0] << s;
+
+    let v_hi = vn[dl - 1] as u128;
+    let v_next = vn[dl - 2] as u128;
+
+    for j in (0..=m - dl).rev() {
+        // Estimate the quotient digit from the top two limbs, then refine
+        // with the next limb; qhat ends at most one too large.
+        let num = (un[j + dl] as u128) << 64 | un[j + dl - 1] as u128;
+        let mut qhat = num / v_hi;
+        let mut rhat = num % v_hi;
+        while qhat > u64::MAX as u128 || qhat * v_next > (rhat << 64 | un[j + dl - 2] as u128) {
+            qhat -= 1;
+            rhat += v_hi;
+            if rhat > u64::MAX as u128 {
+                break;
+            }
+        }
+
+        // Multiply and subtract qhat * vn from un[j..=j+dl]
+        let mut borrow: i128 = 0;
+        let mut carry: u128 = 0;
+        for i in 0..dl {
+            let p = qhat * vn[i] as u128 + carry;
+            carry = p >> 64;
+            let t = un[i + j] as i128 - borrow - (p as u64) as i128;
+            un[i + j] = t as u64;
+            borrow = -(t >> 64);
+        }
+        let t = un[j + dl] as i128 - borrow - carry as i128;
+        un[j + dl] = t as u64;
+
+        // qhat was one too large: add the divisor back
+        if t < 0 {
+            qhat -= 1;
+            let mut c = 0u128;
+            for i in 0..dl {
+                let sum = un[i + j] as u128 + vn[i] as u128 + c;
+                un[i + j] = sum as u64;
+                c = sum >> 64;
+            }
+            un[j + dl] = un[j + dl].wrapping_add(c as u64);
+        }
+        q[j] = qhat as u64;
+    }
+
+    // The remainder is below the normalized divisor, so it fits in un[0..dl]
+    for i in 0..dl {
+        r[i] = (((un[i + 1] as u128) << 64 | un[i] as u128) >> s) as u64;
+    }
+}
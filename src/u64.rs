@@ -29,6 +29,8 @@ pub struct Uint64 {
 
 impl Uint64 {
     pub const ZERO: Self = Self { l: 0, h: 0 };
+    pub const ONE: Self = Self { l: 1, h: 0 };
+    pub const MIN: Self = Self::ZERO;
     pub const MAX: Self = Self {
         l: u32::MAX,
         h: u32::MAX,
@@ -49,10 +51,40 @@ impl Uint64 {
         (self.h as u64) << 32 | self.l as u64
     }
 
+    /// Little-endian byte representation, identical to `u64::to_le_bytes`.
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.to_u64().to_le_bytes()
+    }
+
+    /// Big-endian byte representation, identical to `u64::to_be_bytes`.
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.to_u64().to_be_bytes()
+    }
+
+    /// Create from little-endian bytes, identical to `u64::from_le_bytes`.
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_u64(u64::from_le_bytes(bytes))
+    }
+
+    /// Create from big-endian bytes, identical to `u64::from_be_bytes`.
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_u64(u64::from_be_bytes(bytes))
+    }
+
     pub fn is_zero(&self) -> bool {
         self.l == 0 && self.h == 0
     }
 
+    /// True if the lowest bit is clear. Zero is even.
+    pub const fn is_even(&self) -> bool {
+        self.l & 1 == 0
+    }
+
+    /// True if the lowest bit is set.
+    pub const fn is_odd(&self) -> bool {
+        self.l & 1 == 1
+    }
+
     pub fn leading_zeros(&self) -> u32 {
         if self.h != 0 {
             self.h.leading_zeros()
@@ -60,6 +92,68 @@ impl Uint64 {
             32 + self.l.leading_zeros()
         }
     }
+
+    /// Leading one bits. All-ones yields the full width (64).
+    pub fn leading_ones(&self) -> u32 {
+        (!*self).leading_zeros()
+    }
+
+    /// Minimal number of bits needed to represent the value: 0 for zero,
+    /// otherwise `64 - leading_zeros()`.
+    pub fn bits(&self) -> u32 {
+        64 - self.leading_zeros()
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.l.count_ones() + self.h.count_ones()
+    }
+
+    /// Number of clear bits.
+    pub fn count_zeros(&self) -> u32 {
+        self.l.count_zeros() + self.h.count_zeros()
+    }
+
+    /// Trailing zero bits. Zero yields the full width (64).
+    pub fn trailing_zeros(&self) -> u32 {
+        if self.l != 0 {
+            self.l.trailing_zeros()
+        } else {
+            32 + self.h.trailing_zeros()
+        }
+    }
+
+    /// Trailing one bits. All-ones yields the full width (64).
+    pub fn trailing_ones(&self) -> u32 {
+        if self.l != u32::MAX {
+            self.l.trailing_ones()
+        } else {
+            32 + self.h.trailing_ones()
+        }
+    }
+
+    /// True if exactly one bit is set.
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Smallest power of two greater than or equal to `self`.
+    ///
+    /// Wraps to `ZERO` when that power exceeds `MAX` (like native
+    /// `next_power_of_two` in release builds); `ZERO` rounds up to `ONE`.
+    pub fn next_power_of_two(self) -> Self {
+        if self <= Self::ONE {
+            return Self::ONE;
+        }
+        let p = 64 - (self - Self::ONE).leading_zeros();
+        if p == 64 { Self::ZERO } else { Self::ONE << p }
+    }
+}
+
+impl Default for Uint64 {
+    fn default() -> Self {
+        Self::ZERO
+    }
 }
 
 // ============================================================================
@@ -206,6 +300,9 @@ impl std::ops::Rem for Uint64 {
     }
 }
 
+forward_assign_ops!(Uint64);
+forward_ref_binops!(Uint64);
+
 // ============================================================================
 // Widening operations
 // ============================================================================
@@ -227,6 +324,30 @@ impl Uint64 {
         (Self::from_u64(q), r)
     }
 
+    /// Division by u64 returning (quotient, remainder).
+    ///
+    /// A divisor that fits in 32 bits walks the two limbs from the top, as
+    /// `Uint256::divrem_by_u64` does with u64 limbs: on x86-32 each step is
+    /// one hardware `div` of EDX:EAX by a 32-bit register, where `/` on u64
+    /// would call `__udivdi3`. Larger divisors leave a quotient under 2^32
+    /// and use native u64 division. Elsewhere native division is already a
+    /// single instruction or one `__aeabi_uldivmod` call for both results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is zero, from the native division on either path.
+    #[inline(never)]
+    pub fn divrem_by_u64(self, d: u64) -> (Self, u64) {
+        if cfg!(target_arch = "x86") && d <= u32::MAX as u64 {
+            let d = d as u32;
+            let (h, r) = (self.h / d, self.h % d);
+            let (l, r) = div_u64_by_u32((r as u64) << 32 | self.l as u64, d);
+            return (Self { l, h }, r as u64);
+        }
+        let n = self.to_u64();
+        (Self::from_u64(n / d), n % d)
+    }
+
     /// Full 64×64→128 multiplication.
     ///
     /// LLVM generates:
@@ -269,6 +390,149 @@ impl Uint64 {
     }
 }
 
+// ============================================================================
+// Overflowing, checked, and saturating arithmetic
+// ============================================================================
+
+impl Uint64 {
+    /// Addition returning the wrapped result and the carry out of the high limb.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (l, carry) = self.l.overflowing_add(rhs.l);
+        let (h, carry) = self.h.carrying_add(rhs.h, carry);
+        (Self { l, h }, carry)
+    }
+
+    /// Subtraction returning the wrapped result and the borrow out of the high limb.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (l, borrow) = self.l.overflowing_sub(rhs.l);
+        let (h, borrow) = self.h.borrowing_sub(rhs.h, borrow);
+        (Self { l, h }, borrow)
+    }
+
+    /// Multiplication returning the low 64 bits and whether the high half of
+    /// the full 128-bit product is nonzero.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (hi, lo) = self.widening_mul(rhs);
+        (lo, !hi.is_zero())
+    }
+
+    /// Checked addition. Returns `None` on carry out of the high limb.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked subtraction. Returns `None` on borrow out of the high limb.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked multiplication. Returns `None` if the high half of the
+    /// full 128-bit product is nonzero.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        if overflow { None } else { Some(result) }
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self / rhs) }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { None } else { Some(self % rhs) }
+    }
+
+    /// Wrapping division. Unsigned division cannot overflow, so this is
+    /// `self / rhs`, provided for parity with `u128::wrapping_div`.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Wrapping remainder; the same as `self % rhs`, like
+    /// `u128::wrapping_rem`.
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
+    /// Saturating addition. Clamps to `MAX` on overflow.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Saturating subtraction. Clamps to `ZERO` on underflow.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Self::ZERO)
+    }
+
+    /// Saturating multiplication. Clamps to `MAX` on overflow.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Wrapping exponentiation by squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    /// Checked exponentiation. Returns `None` if the result exceeds `MAX`.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(base)?;
+            }
+            exp >>= 1;
+            // Skip the final squaring: it is never used and may overflow.
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Two's complement negation, `0 - self`, like `u128::wrapping_neg`.
+    pub fn wrapping_neg(self) -> Self {
+        Self::ZERO - self
+    }
+
+    /// Absolute difference `|self - other|`, computed as larger minus
+    /// smaller so it never wraps.
+    pub fn abs_diff(self, other: Self) -> Self {
+        if self > other { self - other } else { other - self }
+    }
+
+    /// Smallest multiple of `rhs` that is `>= self`, like
+    /// `u128::next_multiple_of`. Adds `rhs - self % rhs` rather than
+    /// rounding `self + rhs - 1`, so only a result above `MAX` wraps; use
+    /// `checked_next_multiple_of` to detect that. Panics if `rhs` is zero.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_zero() { self } else { self + (rhs - r) }
+    }
+
+    /// Checked `next_multiple_of`. Returns `None` if `rhs` is zero or the
+    /// result does not fit.
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        let r = self.checked_rem(rhs)?;
+        if r.is_zero() { Some(self) } else { self.checked_add(rhs - r) }
+    }
+}
+
 // ============================================================================
 // Comparison traits
 // ============================================================================
@@ -295,3 +559,137 @@ impl Ord for Uint64 {
         }
     }
 }
+
+ord_methods!(Uint64);
+
+// ============================================================================
+// Bitwise operations
+// ============================================================================
+
+impl std::ops::Not for Uint64 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self {
+            l: !self.l,
+            h: !self.h,
+        }
+    }
+}
+
+impl std::ops::BitAnd for Uint64 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            l: self.l & rhs.l,
+            h: self.h & rhs.h,
+        }
+    }
+}
+
+impl std::ops::BitOr for Uint64 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            l: self.l | rhs.l,
+            h: self.h | rhs.h,
+        }
+    }
+}
+
+impl std::ops::BitXor for Uint64 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self {
+            l: self.l ^ rhs.l,
+            h: self.h ^ rhs.h,
+        }
+    }
+}
+
+// ============================================================================
+// Shifts (logical right shift for unsigned)
+// ============================================================================
+
+impl std::ops::Shl<u32> for Uint64 {
+    type Output = Self;
+
+    fn shl(self, n: u32) -> Self::Output {
+        if n >= 64 {
+            Self::ZERO
+        } else if n >= 32 {
+            Self {
+                l: 0,
+                h: self.l << (n - 32),
+            }
+        } else if n == 0 {
+            self
+        } else {
+            Self {
+                l: self.l << n,
+                h: (self.h << n) | (self.l >> (32 - n)),
+            }
+        }
+    }
+}
+
+impl std::ops::Shr<u32> for Uint64 {
+    type Output = Self;
+
+    /// Logical right shift: fills with zeros.
+    fn shr(self, n: u32) -> Self::Output {
+        if n >= 64 {
+            Self::ZERO
+        } else if n >= 32 {
+            Self {
+                l: self.h >> (n - 32),
+                h: 0,
+            }
+        } else if n == 0 {
+            self
+        } else {
+            Self {
+                l: (self.l >> n) | (self.h << (32 - n)),
+                h: self.h >> n,
+            }
+        }
+    }
+}
+
+forward_bit_assign_ops!(Uint64);
+shift_methods!(Uint64, 64);
+
+// ============================================================================
+// Division helper functions
+// ============================================================================
+
+/// Divide 64-bit by 32-bit, returning (quotient, remainder).
+/// Uses hardware `div` instruction directly for optimal codegen.
+///
+/// Caller must ensure the high half of `n` is below `d`, so the quotient
+/// fits in 32 bits; otherwise `div` raises a divide error, not a panic.
+#[inline]
+#[cfg(target_arch = "x86")]
+fn div_u64_by_u32(n: u64, d: u32) -> (u32, u32) {
+    let q: u32;
+    let r: u32;
+    unsafe {
+        std::arch::asm!(
+            "div {d:e}",
+            d = in(reg) d,
+            inout("eax") n as u32 => q,
+            inout("edx") (n >> 32) as u32 => r,
+            options(pure, nomem, nostack),
+        );
+    }
+    (q, r)
+}
+
+#[inline]
+#[cfg(not(target_arch = "x86"))]
+fn div_u64_by_u32(n: u64, d: u32) -> (u32, u32) {
+    ((n / d as u64) as u32, (n % d as u64) as u32)
+}
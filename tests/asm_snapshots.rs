@@ -165,6 +165,11 @@ asm_snapshot!(
     "<bigints::u64::Uint64 as core::ops::arith::Div>::div",
     "i686-unknown-linux-gnu"
 );
+asm_snapshot!(
+    asm_u64_divrem_by_u64,
+    "<bigints::u64::Uint64>::divrem_by_u64",
+    "i686-unknown-linux-gnu"
+);
 
 asm_snapshot!(
     asm_u128_add,
@@ -613,6 +618,26 @@ asm_matches_native!(
     "bigints::native_div",
     "x86_64-unknown-linux-gnu"
 );
+// Int256::mul must stay a single call into Uint256::mul, so it picks up whatever
+// multiply Uint256 selects and never issues mulx without a BMI2 check of its own.
+#[test]
+fn asm_i256_mul_delegates_x86_64() {
+    let asm = cargo_asm(
+        "<bigints::i256::Int256 as core::ops::arith::Mul>::mul",
+        "x86_64-unknown-linux-gnu",
+    );
+    let body = asm_body(&asm);
+    let calls: Vec<&str> = body
+        .lines()
+        .filter(|l| l.trim_start().starts_with("call"))
+        .collect();
+    assert_eq!(calls.len(), 1, "\n{asm}");
+    assert!(
+        calls[0].contains("<bigints::u256::Uint256 as core::ops::arith::Mul>::mul"),
+        "\n{asm}"
+    );
+    assert!(!body.contains("mulx") && !body.contains("imul"), "\n{asm}");
+}
 
 // aarch64
 asm_snapshot!(